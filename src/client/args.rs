@@ -10,7 +10,7 @@ use crate::{
         FromXml, InitiateMultipartUploadResult, ObjectLockConfiguration, RetentionMode, Tagging,
         ToXml,
     },
-    error::Result,
+    error::{Result, ValueError},
     sse::{Sse, SseCustomerKey},
     time::UtcTime,
     utils::urlencode,
@@ -72,18 +72,18 @@ where
     }
 }
 
-/// A source object definition for `copy_object` and `upload_part_copy` method.
+/// A source object definition for `copy_object`, `compose_object` and `upload_part_copy` method.
 #[derive(Debug, Clone)]
 pub struct CopySource {
-    bucket_name: String,
-    object_name: String,
-    region: Option<String>,
-    offset: usize,
-    length: usize,
-    version_id: Option<String>,
-    metadata_replace: bool,
+    pub(crate) bucket_name: String,
+    pub(crate) object_name: String,
+    pub(crate) region: Option<String>,
+    pub(crate) offset: usize,
+    pub(crate) length: usize,
+    pub(crate) version_id: Option<String>,
+    pub(crate) metadata_replace: bool,
     ssec: Option<HeaderMap>,
-    match_etag: Option<String>,
+    pub(crate) match_etag: Option<String>,
     not_match_etag: Option<String>,
     modified_since: Option<String>,
     unmodified_since: Option<String>,
@@ -113,7 +113,7 @@ impl CopySource {
         self
     }
 
-    /// Used only in `upload_part_copy` method.
+    /// Used only in `compose_object` and `upload_part_copy` method.
     ///
     /// **Note**: length must be greater than 0, or both length and offset are 0.
     pub fn range(mut self, offset: usize, length: usize) -> Self {
@@ -162,6 +162,16 @@ impl CopySource {
         self
     }
 
+    /// Get the SSE-C headers to read the source object directly, without `x-amz-copy-source-*`.
+    pub(crate) fn ssec_read_headers(&self) -> Option<HeaderMap> {
+        self.ssec.as_ref().map(|ssec| {
+            ssec.iter()
+                .filter(|(k, _)| !k.as_str().starts_with("x-amz-copy-source-"))
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect()
+        })
+    }
+
     pub(crate) fn args_headers(&self) -> HeaderMap {
         let mut header = HeaderMap::new();
         let mut copy_source =
@@ -221,6 +231,10 @@ impl CopySource {
 /// - `length`: *Optional*, Number of bytes of object data from offset.
 /// - `metadata`: *Optional*, user-defined metadata.
 /// - `extra_headers`: *Optional*, Extra headers for advanced usage.
+/// - `match_etag`: *Optional*, Only operate the object if its ETag matches.
+/// - `not_match_etag`: *Optional*, Only operate the object if its ETag doesn't match.
+/// - `modified_since`: *Optional*, Only read the object if it has been modified since the time.
+/// - `unmodified_since`: *Optional*, Only read the object if it hasn't been modified since the time.
 ///
 /// **Note**: Some parameters are only valid in specific methods
 #[derive(Debug, Clone)]
@@ -233,6 +247,10 @@ pub struct KeyArgs {
     pub(crate) length: usize,
     pub(crate) extra_headers: Option<HeaderMap>,
     pub(crate) metadata: HashMap<String, String>,
+    pub(crate) match_etag: Option<String>,
+    pub(crate) not_match_etag: Option<String>,
+    pub(crate) modified_since: Option<UtcTime>,
+    pub(crate) unmodified_since: Option<UtcTime>,
 }
 
 impl KeyArgs {
@@ -246,6 +264,10 @@ impl KeyArgs {
             offset: 0,
             length: 0,
             metadata: Default::default(),
+            match_etag: None,
+            not_match_etag: None,
+            modified_since: None,
+            unmodified_since: None,
         }
     }
 
@@ -318,6 +340,79 @@ impl KeyArgs {
         self
     }
 
+    /// Only operate the object if its ETag matches, sent as `If-Match`.
+    /// Valid in `get_object`, `stat_object`, `put_object` and `put_object_stream`,
+    /// including `fput_object` and `fput_object_resumable`.
+    ///
+    /// Otherwise the request fails with [Error::PreconditionFailed](crate::error::Error::PreconditionFailed).
+    pub fn match_etag(mut self, match_etag: Option<String>) -> Self {
+        self.match_etag = match_etag;
+        self
+    }
+
+    /// Only operate the object if its ETag doesn't match, sent as `If-None-Match`.
+    /// Valid in `get_object`, `stat_object`, `put_object` and `put_object_stream`,
+    /// including `fput_object` and `fput_object_resumable`.
+    ///
+    /// Otherwise reading fails with [Error::NotModified](crate::error::Error::NotModified),
+    /// writing fails with [Error::PreconditionFailed](crate::error::Error::PreconditionFailed).
+    pub fn not_match_etag(mut self, not_match_etag: Option<String>) -> Self {
+        self.not_match_etag = not_match_etag;
+        self
+    }
+
+    /// Only write the object if it doesn't exist, sent as `If-None-Match: *`.
+    /// Valid in `put_object` and `put_object_stream`,
+    /// including `fput_object` and `fput_object_resumable`.
+    pub fn create_only(self) -> Self {
+        self.not_match_etag(Some("*".to_string()))
+    }
+
+    /// Only read the object if it has been modified since the time, sent as `If-Modified-Since`.
+    /// Valid in `get_object` and `stat_object`.
+    ///
+    /// Otherwise the request fails with [Error::NotModified](crate::error::Error::NotModified).
+    pub fn modified_since(mut self, modified_since: Option<UtcTime>) -> Self {
+        self.modified_since = modified_since;
+        self
+    }
+
+    /// Only read the object if it hasn't been modified since the time, sent as `If-Unmodified-Since`.
+    /// Valid in `get_object` and `stat_object`.
+    ///
+    /// Otherwise the request fails with [Error::PreconditionFailed](crate::error::Error::PreconditionFailed).
+    pub fn unmodified_since(mut self, unmodified_since: Option<UtcTime>) -> Self {
+        self.unmodified_since = unmodified_since;
+        self
+    }
+
+    /// Get the conditional headers of the preconditions.
+    pub(crate) fn get_condition_header(&self) -> std::result::Result<HeaderMap, ValueError> {
+        let quote = |etag: &str| {
+            if etag == "*" || etag.starts_with('"') || etag.starts_with("W/") {
+                etag.to_string()
+            } else {
+                format!("\"{}\"", etag)
+            }
+        };
+        let mut header = HeaderMap::new();
+        if let Some(etag) = &self.match_etag {
+            header.insert(hyper::header::IF_MATCH, quote(etag).parse()?);
+        }
+        if let Some(etag) = &self.not_match_etag {
+            header.insert(hyper::header::IF_NONE_MATCH, quote(etag).parse()?);
+        }
+        if let Some(time) = &self.modified_since {
+            let value = time.http_format_time().parse()?;
+            header.insert(hyper::header::IF_MODIFIED_SINCE, value);
+        }
+        if let Some(time) = &self.unmodified_since {
+            let value = time.http_format_time().parse()?;
+            header.insert(hyper::header::IF_UNMODIFIED_SINCE, value);
+        }
+        Ok(header)
+    }
+
     /// Returns the metadata header of this [`ObjectArgs`].
     pub(crate) fn get_metadata_header(&self) -> Result<HeaderMap> {
         let mut meta_header: HeaderMap = HeaderMap::new();
@@ -523,6 +618,18 @@ impl ListObjectsArgs {
         self
     }
 
+    /// List recursively if set `true`, otherwise group keys by the `/` delimiter.
+    ///
+    /// This overrides the value set by `delimiter`.
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.delimiter = if recursive {
+            None
+        } else {
+            Some("/".to_string())
+        };
+        self
+    }
+
     /// Set extra headers for advanced usage.
     pub fn extra_headers(mut self, extra_headers: Option<HeaderMap>) -> Self {
         self.extra_headers = extra_headers;
@@ -556,6 +663,125 @@ impl ListObjectsArgs {
     }
 }
 
+/// Custom `list_object_versions` request parameters
+/// ## parmas
+/// - prefix: Limits the response to keys that begin with the specified prefix.
+/// - delimiter: A delimiter is a character you use to group keys.
+/// - key_marker: Specifies the key to start with when listing objects in a bucket.
+/// - version_id_marker: Specifies the object version you want to start listing from.
+/// - max_keys: Sets the maximum number of keys returned in the response. Default 1000
+/// - encoding_type:Encoding type used by Amazon S3 to encode object keys in the response.Valid Values: `url`
+#[derive(Debug, Clone)]
+pub struct ListObjectVersionsArgs {
+    pub(crate) delimiter: Option<String>,
+    pub(crate) use_encoding_type: bool,
+    pub(crate) key_marker: Option<String>,
+    pub(crate) version_id_marker: Option<String>,
+    pub(crate) max_keys: usize,
+    pub(crate) prefix: Option<String>,
+    pub(crate) extra_headers: Option<HeaderMap>,
+}
+
+impl Default for ListObjectVersionsArgs {
+    fn default() -> Self {
+        Self {
+            delimiter: None,
+            use_encoding_type: false,
+            key_marker: None,
+            version_id_marker: None,
+            max_keys: 1000,
+            prefix: None,
+            extra_headers: None,
+        }
+    }
+}
+
+impl ListObjectVersionsArgs {
+    pub fn delimiter<T: Into<String>>(mut self, delimiter: T) -> Self {
+        self.delimiter = Some(delimiter.into());
+        self
+    }
+
+    pub fn use_encoding_type(mut self, use_encoding_type: bool) -> Self {
+        self.use_encoding_type = use_encoding_type;
+        self
+    }
+
+    pub fn key_marker<T: Into<String>>(mut self, key_marker: T) -> Self {
+        self.key_marker = Some(key_marker.into());
+        self
+    }
+
+    pub fn version_id_marker<T: Into<String>>(mut self, version_id_marker: T) -> Self {
+        self.version_id_marker = Some(version_id_marker.into());
+        self
+    }
+
+    pub fn max_keys(mut self, max_keys: usize) -> Self {
+        self.max_keys = max_keys;
+        if self.max_keys > 1000 {
+            self.max_keys = 1000;
+        }
+        self
+    }
+
+    pub fn prefix<T: Into<String>>(mut self, prefix: T) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Set extra headers for advanced usage.
+    pub fn extra_headers(mut self, extra_headers: Option<HeaderMap>) -> Self {
+        self.extra_headers = extra_headers;
+        self
+    }
+
+    pub(crate) fn args_query_map(&self) -> QueryMap {
+        let mut querys: QueryMap = QueryMap::default();
+        querys.insert("versions".to_string(), "".to_string());
+
+        if self.use_encoding_type {
+            querys.insert("encoding-type".to_string(), "url".to_string());
+        }
+        if let Some(delimiter) = &self.delimiter {
+            querys.insert("delimiter".to_string(), delimiter.clone());
+        }
+        if let Some(key_marker) = &self.key_marker {
+            querys.insert("key-marker".to_string(), key_marker.clone());
+        }
+        if let Some(version_id_marker) = &self.version_id_marker {
+            querys.insert("version-id-marker".to_string(), version_id_marker.clone());
+        }
+        if let Some(prefix) = &self.prefix {
+            querys.insert("prefix".to_string(), prefix.clone());
+        }
+        querys.insert("max-keys".to_string(), format!("{}", self.max_keys));
+        querys
+    }
+}
+
+/// Custom `remove_objects` request parameters
+/// ## parmas
+/// - quiet: if set `true`, only the keys that failed to delete are returned. Default false
+/// - bypass_governance: bypass Governance-mode Object Lock restrictions. Default false
+#[derive(Debug, Clone, Default)]
+pub struct RemoveObjectsArgs {
+    pub(crate) quiet: bool,
+    pub(crate) bypass_governance: bool,
+}
+
+impl RemoveObjectsArgs {
+    pub fn quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    pub fn bypass_governance(mut self, bypass_governance: bool) -> Self {
+        self.bypass_governance = bypass_governance;
+        self
+    }
+}
+
 /// Custom request parameters for multiUpload operations.
 ///
 /// Used in `abort_multipart_upload`, `complete_multipart_upload`, `create_multipart_upload`,
@@ -739,6 +965,7 @@ impl FromXml for ObjectLockConfig {
 /// - request_date: Optional request_date argument to specify a different request date. Default is current date.
 /// - version_id: Version ID of the object.
 /// - querys: Extra query parameters for advanced usage.
+/// - signed_headers: Headers bound into the signature, which the client must send.
 #[derive(Clone)]
 pub struct PresignedArgs {
     pub(crate) region: Option<String>,
@@ -749,6 +976,7 @@ pub struct PresignedArgs {
     pub(crate) request_date: Option<UtcTime>,
     pub(crate) headers: Option<HeaderMap>,
     pub(crate) querys: QueryMap,
+    pub(crate) signed_headers: HeaderMap,
 }
 
 impl PresignedArgs {
@@ -762,6 +990,7 @@ impl PresignedArgs {
             request_date: None,
             headers: None,
             querys: QueryMap::new(),
+            signed_headers: HeaderMap::new(),
         }
     }
 
@@ -817,6 +1046,29 @@ impl PresignedArgs {
         self
     }
 
+    /// Bind the headers into the signature, the client must send them with the same values.
+    pub fn signed_headers(mut self, headers: HeaderMap) -> Self {
+        self.signed_headers.extend(headers);
+        self
+    }
+
+    /// Bind the header into the signature, like `content-type` or `x-amz-meta-*`,
+    /// the client must send it with the same value.
+    ///
+    /// Returns [ValueError] if `value` is not a valid header value.
+    pub fn signed_header<K>(mut self, key: K, value: &str) -> std::result::Result<Self, ValueError>
+    where
+        K: IntoHeaderName,
+    {
+        self.signed_headers.insert(key, value.parse()?);
+        Ok(self)
+    }
+
+    /// Bind the server-side encryption customer key headers into the signature.
+    pub fn ssec(self, ssec: &SseCustomerKey) -> Self {
+        self.signed_headers(ssec.headers())
+    }
+
     pub fn apply<F>(self, apply: F) -> Self
     where
         F: FnOnce(Self) -> Self,
@@ -4,12 +4,15 @@ use std::pin::Pin;
 use bytes::Bytes;
 use futures_core::Stream;
 use hyper::Method;
-use reqwest::Response;
 
-use super::{BucketArgs, CopySource, KeyArgs, ListObjectsArgs, ObjectLockConfig, Tags};
-use super::{ObjectStat, SelectObjectReader};
-use crate::datatype::{Retention, ListBucketResult};
-use crate::datatype::SelectRequest;
+use super::{BucketArgs, CopySource, KeyArgs, ListObjectVersionsArgs, ListObjectVersionsItem};
+use super::{GetObjectOutput, ObjectStat, SelectObjectReader};
+use super::{ListObjectsArgs, ListObjectsItem, ObjectLockConfig, Tags};
+use super::{RemoveObjectsArgs, RemoveObjectsItem};
+use crate::datatype::{CorsConfiguration, ServerSideEncryptionConfiguration};
+use crate::datatype::{LifecycleConfiguration, ListBucketResult, ListVersionsResult, Retention};
+use crate::datatype::{NotificationConfiguration, NotificationRecord};
+use crate::datatype::{PolicyDocument, ReplicationConfiguration, SelectRequest};
 use crate::{error::Result, Minio};
 
 /// Instantiate an Bucket which wrap [Minio] and [BucketArgs].
@@ -70,16 +73,99 @@ impl Bucket {
     }
 
     proxy_bucket!(list_objects, ListBucketResult, ListObjectsArgs);
+
+    /// Lists all objects of the bucket as a stream, see [Minio::list_objects_stream].
+    #[inline]
+    pub fn list_objects_stream(
+        &self,
+        args: ListObjectsArgs,
+    ) -> Pin<Box<dyn Stream<Item = Result<ListObjectsItem>> + Send>> {
+        self.client.list_objects_stream(self.bucket.clone(), args)
+    }
+
+    proxy_bucket!(
+        list_object_versions,
+        ListVersionsResult,
+        ListObjectVersionsArgs
+    );
+
+    /// Lists all versions and delete markers of the bucket as a stream,
+    /// see [Minio::list_object_versions_stream].
+    #[inline]
+    pub fn list_object_versions_stream(
+        &self,
+        args: ListObjectVersionsArgs,
+    ) -> Pin<Box<dyn Stream<Item = Result<ListObjectVersionsItem>> + Send>> {
+        self.client
+            .list_object_versions_stream(self.bucket.clone(), args)
+    }
+
     proxy_bucket!(delete_object_lock_config, ());
     proxy_bucket!(get_object_lock_config, ObjectLockConfig);
     proxy_bucket!(set_object_lock_config, (), ObjectLockConfig);
 
-    proxy_object!(get_object, Response);
+    proxy_bucket!(get_bucket_policy, Option<PolicyDocument>);
+    proxy_bucket!(set_bucket_policy, (), PolicyDocument);
+    proxy_bucket!(delete_bucket_policy, ());
+
+    proxy_bucket!(get_bucket_lifecycle, Option<LifecycleConfiguration>);
+    proxy_bucket!(set_bucket_lifecycle, (), LifecycleConfiguration);
+    proxy_bucket!(delete_bucket_lifecycle, ());
+
+    proxy_bucket!(
+        get_bucket_encryption,
+        Option<ServerSideEncryptionConfiguration>
+    );
+    proxy_bucket!(set_bucket_encryption, (), ServerSideEncryptionConfiguration);
+    proxy_bucket!(delete_bucket_encryption, ());
+
+    proxy_bucket!(get_bucket_replication, Option<ReplicationConfiguration>);
+    proxy_bucket!(set_bucket_replication, (), ReplicationConfiguration);
+    proxy_bucket!(delete_bucket_replication, ());
+
+    proxy_bucket!(get_bucket_cors, Option<CorsConfiguration>);
+    proxy_bucket!(set_bucket_cors, (), CorsConfiguration);
+    proxy_bucket!(delete_bucket_cors, ());
+
+    proxy_bucket!(get_bucket_notification, NotificationConfiguration);
+    proxy_bucket!(set_bucket_notification, (), NotificationConfiguration);
+    proxy_bucket!(delete_bucket_notification, ());
+
+    /// Listen the events of objects in the bucket as a stream,
+    /// see [Minio::listen_bucket_notification].
+    #[inline]
+    pub fn listen_bucket_notification(
+        &self,
+        prefix: &str,
+        suffix: &str,
+        events: Vec<String>,
+    ) -> Pin<Box<dyn Stream<Item = Result<NotificationRecord>> + Send>> {
+        self.client
+            .listen_bucket_notification(self.bucket.clone(), prefix, suffix, events)
+    }
+
+    proxy_object!(get_object, GetObjectOutput);
     proxy_object!(put_object, (), data=>Bytes);
     proxy_object!(put_object_stream, (), stream=>FsStream, len=>Option<usize>);
     proxy_object!(copy_object, (), cp=> CopySource);
+    proxy_object!(compose_object, (), sources=> Vec<CopySource>);
     proxy_object!(remove_object, ());
     proxy_object!(stat_object, Option<ObjectStat>);
+
+    /// Remove multiple objects of the bucket, see [Minio::remove_objects].
+    #[inline]
+    pub fn remove_objects<S, K>(
+        &self,
+        objects: S,
+        args: RemoveObjectsArgs,
+    ) -> Pin<Box<dyn Stream<Item = Result<RemoveObjectsItem>> + Send>>
+    where
+        S: Stream<Item = K> + Send + 'static,
+        K: Into<KeyArgs> + 'static,
+    {
+        self.client
+            .remove_objects(self.bucket.clone(), objects, args)
+    }
     proxy_object!(is_object_legal_hold_enabled, bool);
     proxy_object!(enable_object_legal_hold_enabled, ());
     proxy_object!(disable_object_legal_hold_enabled, ());
@@ -113,6 +199,19 @@ impl Bucket {
             .fput_object(self.bucket.clone(), key, path)
             .await
     }
+
+    #[cfg(feature = "fs-tokio")]
+    #[inline]
+    pub async fn fput_object_resumable<K, P, S>(&self, key: K, path: P, store: &S) -> Result<()>
+    where
+        K: Into<KeyArgs>,
+        P: AsRef<Path>,
+        S: super::CheckpointStore + ?Sized,
+    {
+        self.client
+            .fput_object_resumable(self.bucket.clone(), key, path, store)
+            .await
+    }
 }
 
 impl Into<BucketArgs> for Bucket {
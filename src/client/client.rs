@@ -1,19 +1,21 @@
+use std::collections::HashMap;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 use crate::data::Data;
-use crate::error::{Error, Result, ValueError};
+use crate::error::{Error, Result, S3Error, ValueError};
 use crate::provider::Provider;
 use crate::signer::sign_request_v4;
 use crate::utils::{check_bucket_name, urlencode};
 use crate::Credentials;
 use async_mutex::Mutex;
+use bytes::Bytes;
 use hyper::{header, header::HeaderValue, HeaderMap};
-use hyper::{Body, Method, Uri};
+use hyper::{Body, Method, StatusCode, Uri};
 use regex::Regex;
-use reqwest::Response;
+use reqwest::{Response, ResponseBuilderExt};
 
-use super::{Bucket, BucketArgs};
+use super::{Bucket, BucketArgs, RetryPolicy, UploadConfig};
 
 /// A `MinioBuilder` can be used to create a [`Minio`] with custom configuration.
 pub struct MinioBuilder {
@@ -21,13 +23,17 @@ pub struct MinioBuilder {
     // access_key: Option<String>,
     // secret_key: Option<String>,
     // session_token: Option<String>,
-    region: String,
+    region: Option<String>,
     agent: String,
     secure: bool,
     virtual_hosted: bool,
     multi_chunked_encoding: bool,
     provider: Option<Box<Mutex<dyn Provider>>>,
     client: Option<reqwest::Client>,
+    retry: RetryPolicy,
+    upload: UploadConfig,
+    #[cfg(feature = "fs-tokio")]
+    download: super::DownloadConfig,
 }
 
 impl MinioBuilder {
@@ -37,10 +43,14 @@ impl MinioBuilder {
             secure: true,
             virtual_hosted: false,
             multi_chunked_encoding: true,
-            region: "us-east-1".to_string(),
+            region: None,
             agent: "MinIO (Linux; x86_64) minio-rs".to_string(),
             provider: None,
             client: None,
+            retry: RetryPolicy::default(),
+            upload: UploadConfig::default(),
+            #[cfg(feature = "fs-tokio")]
+            download: Default::default(),
         }
     }
 
@@ -68,9 +78,10 @@ impl MinioBuilder {
 
     /// Set region name of buckets in S3 service.
     ///
-    /// Default: `us-east-1`
+    /// If not set, the region of each bucket is discovered by `GetBucketLocation` and cached,
+    /// `us-east-1` is used for requests without bucket.
     pub fn region<T: Into<String>>(mut self, region: T) -> Self {
-        self.region = region.into();
+        self.region = Some(region.into());
         self
     }
 
@@ -117,6 +128,31 @@ impl MinioBuilder {
         self
     }
 
+    /// Set retry policy of the requests.
+    ///
+    /// Default: [RetryPolicy::default()], use [RetryPolicy::none()] to disable retries.
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Set multipart upload configuration of `put_object_stream` and `fput_object`.
+    ///
+    /// Default: [UploadConfig::default()].
+    pub fn upload_config(mut self, upload: UploadConfig) -> Self {
+        self.upload = upload;
+        self
+    }
+
+    /// Set ranged download configuration of `fget_object`.
+    ///
+    /// Default: [DownloadConfig::default()](super::DownloadConfig::default).
+    #[cfg(feature = "fs-tokio")]
+    pub fn download_config(mut self, download: super::DownloadConfig) -> Self {
+        self.download = download;
+        self
+    }
+
     /// Set credentials provider of your account in S3 service.
     ///
     /// **Required**.
@@ -158,9 +194,15 @@ impl MinioBuilder {
                 client2,
                 virtual_hosted: self.virtual_hosted,
                 multi_chunked: self.multi_chunked_encoding,
-                region: self.region,
+                region_lookup: self.region.is_none(),
+                region: self.region.unwrap_or_else(|| "us-east-1".to_string()),
+                region_cache: Default::default(),
                 agent,
                 provider,
+                retry: self.retry,
+                upload: self.upload,
+                #[cfg(feature = "fs-tokio")]
+                download: self.download,
             }),
         })
     }
@@ -195,8 +237,14 @@ struct MinioRef {
     secure: bool,
     client2: reqwest::Client,
     region: String,
+    region_lookup: bool,
+    region_cache: RwLock<HashMap<String, String>>,
     agent: HeaderValue,
     provider: Box<Mutex<dyn Provider>>,
+    retry: RetryPolicy,
+    upload: UploadConfig,
+    #[cfg(feature = "fs-tokio")]
+    download: super::DownloadConfig,
 }
 
 impl Minio {
@@ -210,12 +258,74 @@ impl Minio {
         self.inner.multi_chunked
     }
 
+    /// return the multipart upload configuration.
+    #[inline]
+    pub(crate) fn upload_config(&self) -> &UploadConfig {
+        &self.inner.upload
+    }
+
+    /// return the ranged download configuration.
+    #[cfg(feature = "fs-tokio")]
+    #[inline]
+    pub(crate) fn download_config(&self) -> &super::DownloadConfig {
+        &self.inner.download
+    }
+
     pub fn region(&self) -> &str {
         self.inner.region.as_ref()
     }
 
-    fn _get_region<T: Into<String>>(&self, bucket_name: Option<T>) -> String {
-        self.inner.region.clone()
+    /// Get region of the bucket.
+    ///
+    /// If the region of [MinioBuilder] is not set, the region is discovered by `GetBucketLocation`
+    /// and cached, fall back to `us-east-1` if the location is not accessible.
+    /// The fallback is not cached after a transient error like `SlowDown`.
+    pub(crate) async fn _get_region(&self, bucket_name: &str) -> Result<String> {
+        if !self.inner.region_lookup {
+            return Ok(self.inner.region.clone());
+        }
+        if let Ok(cache) = self.inner.region_cache.read() {
+            if let Some(region) = cache.get(bucket_name) {
+                return Ok(region.clone());
+            }
+        }
+        match self.get_bucket_location(bucket_name).await {
+            Ok(region) => {
+                self._cache_region(bucket_name, Some(region.clone()));
+                Ok(region)
+            }
+            Err(Error::S3Error(e)) => {
+                // cache the fallback only if the location won't be accessible by retrying,
+                // avoid asking for the location before every request.
+                if matches!(
+                    e.code.as_str(),
+                    "AccessDenied" | "NoSuchBucket" | "NotImplemented"
+                ) {
+                    self._cache_region(bucket_name, Some(self.inner.region.clone()));
+                }
+                Ok(self.inner.region.clone())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Update the region cache of bucket, remove it if `region` is [None].
+    pub(crate) fn _cache_region(&self, bucket_name: &str, region: Option<String>) {
+        if !self.inner.region_lookup {
+            return;
+        }
+        if let Ok(mut cache) = self.inner.region_cache.write() {
+            match region {
+                Some(region) => cache.insert(bucket_name.to_string(), region),
+                None => cache.remove(bucket_name),
+            };
+        }
+    }
+
+    /// Return whether the region of bucket is discovered automatically.
+    #[inline]
+    pub(crate) fn region_lookup(&self) -> bool {
+        self.inner.region_lookup
     }
 
     #[inline]
@@ -311,18 +421,111 @@ impl Minio {
         }
         let mut headers = headers.unwrap_or(HeaderMap::new());
         headers.insert(header::USER_AGENT, self.inner.agent.clone());
-        let credentials = self.fetch_credentials().await;
         let uri = Uri::from_str(&uri).map_err(|e| Error::ValueError(e.to_string()))?;
+        let data = match data {
+            Data::Bytes(data) => data,
+            // stream body is not replayable, send only once.
+            data => {
+                return self
+                    ._sign_and_send(&method, &uri, headers, region, data)
+                    .await
+            }
+        };
+        let retry = &self.inner.retry;
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let last_attempt = attempt >= retry.attempts();
+            let data = Data::Bytes(data.clone());
+            let res = match self
+                ._sign_and_send(&method, &uri, headers.clone(), region, data)
+                .await
+            {
+                Ok(res) => res,
+                Err(Error::HttpError(_)) if !last_attempt => {
+                    tokio::time::sleep(retry.delay(attempt)).await;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            let status = res.status();
+            if last_attempt || !(status.is_client_error() || status.is_server_error()) {
+                return Ok(res);
+            }
+            if retry.is_retryable_status(status.as_u16()) {
+                tokio::time::sleep(retry.delay(attempt)).await;
+                continue;
+            }
+            // read the body to check the S3 error code.
+            let (res, body) = buffer_response(res).await?;
+            let retryable = S3Error::try_from(body.as_ref())
+                .map(|e| retry.is_retryable_code(&e.code))
+                .unwrap_or(false);
+            if retryable {
+                tokio::time::sleep(retry.delay(attempt)).await;
+                continue;
+            }
+            return Ok(res);
+        }
+    }
+
+    /// Check whether the response indicates the request was sent to the wrong region of bucket,
+    /// by `301` redirect or `AuthorizationHeaderMalformed` error.
+    ///
+    /// Return the response and the right region.
+    pub(super) async fn _region_redirect(
+        &self,
+        res: Response,
+        region: &str,
+    ) -> Result<(Response, Option<String>)> {
+        let status = res.status();
+        if status != StatusCode::MOVED_PERMANENTLY && status != StatusCode::BAD_REQUEST {
+            return Ok((res, None));
+        }
+        let bucket_region = res
+            .headers()
+            .get("x-amz-bucket-region")
+            .and_then(|r| r.to_str().ok())
+            .map(String::from);
+        let (res, bucket_region) = match bucket_region {
+            Some(r) => (res, Some(r)),
+            None if status == StatusCode::BAD_REQUEST => {
+                let (res, body) = buffer_response(res).await?;
+                let bucket_region = S3Error::try_from(body.as_ref())
+                    .ok()
+                    .filter(|e| e.code == "AuthorizationHeaderMalformed")
+                    .and_then(|e| e.region);
+                (res, bucket_region)
+            }
+            None => (res, None),
+        };
+        let bucket_region = bucket_region.filter(|r| !r.is_empty() && r != region);
+        Ok((res, bucket_region))
+    }
+
+    /// Sign the request with fresh credentials and date, then send it.
+    async fn _sign_and_send(
+        &self,
+        method: &Method,
+        uri: &Uri,
+        mut headers: HeaderMap,
+        region: &str,
+        data: Data<Error>,
+    ) -> Result<Response> {
+        let credentials = self.fetch_credentials().await;
+        if let Some(token) = credentials.session_token() {
+            headers.insert("x-amz-security-token", token.parse()?);
+        }
         let (uri, headers, body) = sign_request_v4(
-            &method,
-            &uri,
+            method,
+            uri,
             headers,
             region,
             data,
             credentials.access_key(),
             credentials.secret_key(),
         )?;
-        self._url_open(method, uri, headers, body).await
+        self._url_open(method.clone(), uri, headers, body).await
     }
 
     #[inline]
@@ -341,3 +544,20 @@ impl Minio {
         }
     }
 }
+
+/// Read the body of response, return a rebuilt response with the same body and the body.
+async fn buffer_response(res: Response) -> Result<(Response, Bytes)> {
+    let status = res.status();
+    let version = res.version();
+    let url = res.url().clone();
+    let headers = res.headers().clone();
+    let body = res.bytes().await?;
+    let mut rebuilt = hyper::Response::builder()
+        .status(status)
+        .version(version)
+        .url(url)
+        .body(body.clone())
+        .map_err(|e| ValueError::new(e.to_string()))?;
+    *rebuilt.headers_mut() = headers;
+    Ok((rebuilt.into(), body))
+}
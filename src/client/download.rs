@@ -0,0 +1,133 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use futures::StreamExt;
+use reqwest::Response;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+use crate::error::{Result, ValueError};
+
+/// Ranged download configuration of `fget_object`.
+///
+/// The object is downloaded in ranges of `part_size` bytes,
+/// keeping up to `max_concurrency` ranges downloading at the same time.
+/// ## Example
+/// ```rust
+/// use minio_rsc::client::DownloadConfig;
+/// let config = DownloadConfig::default()
+///     .part_size(32 * 1024 * 1024)
+///     .max_concurrency(8);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DownloadConfig {
+    pub(crate) part_size: usize,
+    pub(crate) max_concurrency: usize,
+}
+
+impl DownloadConfig {
+    /// Set the byte length of each range.
+    ///
+    /// Default: `16MiB`
+    pub fn part_size(mut self, part_size: usize) -> Self {
+        self.part_size = part_size.max(1);
+        self
+    }
+
+    /// Set the maximum number of ranges downloaded at the same time.
+    ///
+    /// Default: `4`
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+}
+
+impl Default for DownloadConfig {
+    fn default() -> Self {
+        Self {
+            part_size: 16 * 1024 * 1024,
+            max_concurrency: 4,
+        }
+    }
+}
+
+/// Progress of an interrupted download, saved next to the `.part` file.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct DownloadState {
+    pub etag: String,
+    pub size: usize,
+    pub part_size: usize,
+    /// indexes of the ranges written to the `.part` file.
+    pub done: Vec<usize>,
+}
+
+impl DownloadState {
+    pub fn new(etag: &str, size: usize, part_size: usize) -> Self {
+        Self {
+            etag: etag.to_string(),
+            size,
+            part_size,
+            done: vec![],
+        }
+    }
+
+    /// Load the state, `None` if not exists or corrupted.
+    pub async fn load(path: &Path) -> Option<Self> {
+        let data = tokio::fs::read(path).await.ok()?;
+        serde_json::from_slice(&data).ok()
+    }
+
+    pub async fn save(&self, path: &Path) -> std::io::Result<()> {
+        tokio::fs::write(path, serde_json::to_vec(self)?).await
+    }
+}
+
+/// Append `suffix` to the file name of `path`.
+pub(crate) fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path: OsString = path.as_os_str().to_owned();
+    path.push(suffix);
+    path.into()
+}
+
+/// Write the body of `res` into the file at `offset`, which must be `length` bytes.
+pub(crate) async fn write_range(
+    path: &Path,
+    offset: usize,
+    length: usize,
+    res: Response,
+) -> Result<()> {
+    let mut file = tokio::fs::OpenOptions::new().write(true).open(path).await?;
+    file.seek(std::io::SeekFrom::Start(offset as u64)).await?;
+    let mut written = 0;
+    let mut stream = res.bytes_stream();
+    while let Some(data) = stream.next().await {
+        let data = data?;
+        written += data.len();
+        file.write_all(&data).await?;
+    }
+    if written != length {
+        return Err(ValueError::new(format!(
+            "expected {} bytes at offset {}, but received {}",
+            length, offset, written
+        ))
+        .into());
+    }
+    file.sync_data().await?;
+    Ok(())
+}
+
+/// Calculate the md5 of the file, in hex.
+pub(crate) async fn file_md5(path: &Path) -> std::io::Result<String> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut context = md5::Context::new();
+    let mut buf = vec![0; 1024 * 1024];
+    loop {
+        let n = file.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        context.consume(&buf[..n]);
+    }
+    Ok(format!("{:x}", context.compute()))
+}
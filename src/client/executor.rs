@@ -41,7 +41,7 @@ use crate::utils::md5sum_hash;
 /// ```
 pub struct BaseExecutor<'a> {
     method: Method,
-    region: String,
+    region: Option<String>,
     bucket_name: Option<String>,
     object_name: Option<String>,
     body: Data<Error>,
@@ -55,7 +55,7 @@ impl<'a> BaseExecutor<'a> {
     pub fn new(method: Method, client: &'a Minio) -> Self {
         return Self {
             method,
-            region: client.region().to_string(),
+            region: None,
             bucket_name: None,
             object_name: None,
             body: Default::default(),
@@ -85,8 +85,10 @@ impl<'a> BaseExecutor<'a> {
     }
 
     /// Set the region.
+    ///
+    /// If not set, the region of bucket is used, see [MinioBuilder::region](crate::client::MinioBuilder::region).
     pub fn region<T: Into<String>>(mut self, region: T) -> Self {
-        self.region = region.into();
+        self.region = Some(region.into());
         self
     }
 
@@ -194,14 +196,63 @@ impl<'a> BaseExecutor<'a> {
     /// note: this is just a response from the s3 service, probably a wrong response.
     pub async fn send(self) -> Result<Response> {
         self.build_err?;
-        let query = self.querys.into();
-        self.client
+        let query: String = self.querys.into();
+        let client = self.client;
+        let bucket_region = match (&self.region, &self.bucket_name) {
+            (None, Some(bucket)) if client.region_lookup() => {
+                Some(client._get_region(bucket).await?)
+            }
+            _ => None,
+        };
+        let region = match (self.region, &bucket_region) {
+            (Some(region), _) => region,
+            (None, Some(region)) => region.clone(),
+            (None, None) => client.region().to_string(),
+        };
+        let body = match self.body {
+            // stream body is not replayable, the region redirect cannot be resent.
+            Data::Bytes(body) if bucket_region.is_some() => body,
+            body => {
+                return client
+                    ._execute(
+                        self.method,
+                        &region,
+                        self.bucket_name,
+                        self.object_name,
+                        body,
+                        Some(self.headers),
+                        Some(query),
+                    )
+                    .await
+            }
+        };
+        let res = client
+            ._execute(
+                self.method.clone(),
+                &region,
+                self.bucket_name.clone(),
+                self.object_name.clone(),
+                body.clone(),
+                Some(self.headers.clone()),
+                Some(query.clone()),
+            )
+            .await?;
+        let (res, new_region) = client._region_redirect(res, &region).await?;
+        let new_region = match new_region {
+            Some(new_region) => new_region,
+            None => return Ok(res),
+        };
+        // the cached region is out of date, resend with the right region.
+        if let Some(bucket) = &self.bucket_name {
+            client._cache_region(bucket, Some(new_region.clone()));
+        }
+        client
             ._execute(
                 self.method,
-                &self.region,
+                &new_region,
                 self.bucket_name,
                 self.object_name,
-                self.body,
+                body,
                 Some(self.headers),
                 Some(query),
             )
@@ -211,10 +262,16 @@ impl<'a> BaseExecutor<'a> {
     /// Send an HTTP request to S3 and return a Result<[Response]>.
     ///
     /// This checks if the request is a legitimate S3 response.
+    ///
+    /// `304` and `412` responses are returned as [Error::NotModified] and [Error::PreconditionFailed].
     pub async fn send_ok(self) -> Result<Response> {
         let res = self.send().await?;
         if res.status().is_success() {
             Ok(res)
+        } else if res.status() == 304 {
+            Err(Error::NotModified)
+        } else if res.status() == 412 {
+            Err(Error::PreconditionFailed)
         } else {
             let text = res.text().await?;
             let s: S3Error = text.as_str().try_into()?;
@@ -2,22 +2,40 @@
 mod args;
 mod bucket;
 mod client;
+#[cfg(feature = "fs-tokio")]
+mod download;
 mod executor;
 mod mutilpart_upload;
 mod operate_bucket;
 mod operate_object;
+mod post_policy;
 mod presigned;
 mod querymap;
 mod response;
+mod resumable;
+mod retry;
 mod select_object_reader;
+mod upload;
 
 pub use args::{
-    BucketArgs, CopySource, KeyArgs, ListMultipartUploadsArgs, ListObjectsArgs,
-    MultipartUploadTask, ObjectLockConfig, PresignedArgs, Tags,
+    BucketArgs, CopySource, KeyArgs, ListMultipartUploadsArgs, ListObjectVersionsArgs,
+    ListObjectsArgs, MultipartUploadTask, ObjectLockConfig, PresignedArgs, RemoveObjectsArgs, Tags,
 };
 pub use bucket::Bucket;
 pub use client::*;
+#[cfg(feature = "fs-tokio")]
+pub use download::DownloadConfig;
 pub use executor::BaseExecutor;
+pub use post_policy::PostPolicy;
 pub use querymap::QueryMap;
-pub use response::ObjectStat;
+#[cfg(feature = "io-tokio")]
+pub use response::GetObjectReader;
+pub use response::{
+    GetObjectOutput, ListObjectVersionsItem, ListObjectsItem, ObjectStat, RemoveObjectsItem,
+};
+#[cfg(feature = "fs-tokio")]
+pub use resumable::FileCheckpointStore;
+pub use resumable::{CheckpointFuture, CheckpointStore, MemoryCheckpointStore, UploadCheckpoint};
+pub use retry::RetryPolicy;
 pub use select_object_reader::{Message, SelectObjectReader};
+pub use upload::UploadConfig;
@@ -1,4 +1,8 @@
-use bytes::Bytes;
+use std::pin::Pin;
+
+use async_stream::stream;
+use bytes::{Bytes, BytesMut};
+use futures::{Stream, StreamExt};
 use hyper::{header, HeaderMap, Method};
 
 use super::args::MultipartUploadTask;
@@ -13,6 +17,66 @@ use crate::Minio;
 
 /// Operating multiUpload
 impl Minio {
+    /// Upload parts read from stream, keeping up to `concurrency` `upload_part` requests in flight.
+    ///
+    /// The stream is read while the parts are uploading.
+    /// Returns the uploaded parts ordered by part number.
+    /// An empty part is uploaded if the stream is empty.
+    pub(crate) async fn _upload_parts(
+        &self,
+        task: &MultipartUploadTask,
+        mut stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>,
+        part_size: usize,
+        concurrency: usize,
+    ) -> Result<Vec<Part>> {
+        let chunks = stream! {
+            let mut part_number = 0;
+            let mut current = BytesMut::with_capacity(part_size);
+            loop {
+                let piece = match stream.next().await.transpose() {
+                    Ok(piece) => piece,
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                };
+                if let Some(piece) = &piece {
+                    current.extend_from_slice(piece);
+                }
+                let eof = piece.is_none();
+                while current.len() >= part_size
+                    || (eof && (!current.is_empty() || part_number == 0))
+                {
+                    let data = if current.len() >= part_size {
+                        current.split_to(part_size).freeze()
+                    } else {
+                        current.split().freeze()
+                    };
+                    part_number += 1;
+                    yield Ok((part_number, data));
+                }
+                if eof {
+                    break;
+                }
+            }
+        };
+        // `buffer_unordered` polls the chunks and the uploading parts together.
+        let mut uploading = Box::pin(
+            chunks
+                .map(|chunk: Result<(usize, Bytes)>| async move {
+                    let (part_number, data) = chunk?;
+                    self.upload_part(task, part_number, data).await
+                })
+                .buffer_unordered(concurrency),
+        );
+        let mut parts = Vec::new();
+        while let Some(part) = uploading.next().await {
+            parts.push(part?);
+        }
+        parts.sort_by_key(|p: &Part| p.part_number);
+        Ok(parts)
+    }
+
     /// Aborts a multipart upload.
     pub async fn abort_multipart_upload(&self, task: &MultipartUploadTask) -> Result<()> {
         let res = self
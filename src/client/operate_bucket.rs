@@ -1,12 +1,27 @@
+use std::pin::Pin;
+
+use async_stream::stream;
+use futures::StreamExt;
+use futures_core::Stream;
 use hyper::header;
 use hyper::Method;
+use serde::Deserialize;
 
 use super::args::ObjectLockConfig;
-use super::{BucketArgs, ListObjectsArgs, Tags};
+use super::{BucketArgs, ListObjectVersionsArgs, ListObjectVersionsItem};
+use super::{ListObjectsArgs, ListObjectsItem, QueryMap, Tags};
+use crate::data::Data;
+use crate::datatype::CorsConfiguration;
+use crate::datatype::LifecycleConfiguration;
 use crate::datatype::ListAllMyBucketsResult;
 use crate::datatype::ListBucketResult;
-use crate::datatype::{Bucket, Owner, VersioningConfiguration};
-use crate::error::{Error, Result};
+use crate::datatype::ListVersionsResult;
+use crate::datatype::ReplicationConfiguration;
+use crate::datatype::ServerSideEncryptionConfiguration;
+use crate::datatype::{Bucket, Owner, PolicyDocument, VersioningConfiguration};
+use crate::datatype::{NotificationConfiguration, NotificationRecord};
+use crate::error::{Error, Result, S3Error, ValueError};
+use crate::utils::parse_location_constraint;
 use crate::Minio;
 
 macro_rules! get_attr {
@@ -44,12 +59,14 @@ impl Minio {
         self.executor(method)
             .bucket_name(&bucket.name)
             .headers_merge2(bucket.extra_headers)
-            .apply(|e| {
+            .apply(|mut e| {
+                if let Some(region) = &bucket.region {
+                    e = e.region(region);
+                }
                 if let Some(owner) = &bucket.expected_bucket_owner {
-                    e.header("x-amz-expected-bucket-owner", owner)
-                } else {
-                    e
+                    e = e.header("x-amz-expected-bucket-owner", owner);
                 }
+                e
             })
     }
 
@@ -119,6 +136,133 @@ impl Minio {
             .await
     }
 
+    /// Lists all objects of a bucket as a stream,
+    /// following `NextContinuationToken` until the listing is complete.
+    ///
+    /// Common prefixes are yielded as [ListObjectsItem::CommonPrefix] when a delimiter is set.
+    /// ## Example
+    /// ```rust
+    /// use futures::StreamExt;
+    /// use minio_rsc::client::{ListObjectsArgs, ListObjectsItem};
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let args = ListObjectsArgs::default().prefix("photos/").recursive(true);
+    /// let mut stream = minio.list_objects_stream("bucket", args);
+    /// while let Some(item) = stream.next().await {
+    ///     if let ListObjectsItem::Object(object) = item? {
+    ///         println!("{}", object.key);
+    ///     }
+    /// }
+    /// # Ok(())}
+    /// ```
+    pub fn list_objects_stream<B>(
+        &self,
+        bucket: B,
+        mut args: ListObjectsArgs,
+    ) -> Pin<Box<dyn Stream<Item = Result<ListObjectsItem>> + Send>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let minio = self.clone();
+        Box::pin(stream! {
+            loop {
+                let result = minio.list_objects(bucket.clone(), args.clone()).await?;
+                for prefix in result.common_prefixes {
+                    yield Ok(ListObjectsItem::CommonPrefix(prefix));
+                }
+                for object in result.contents {
+                    yield Ok(ListObjectsItem::Object(object));
+                }
+                if !result.is_truncated || result.next_continuation_token.is_empty() {
+                    break;
+                }
+                args.continuation_token = Some(result.next_continuation_token);
+            }
+        })
+    }
+
+    /// Lists metadata about all versions and delete markers of the objects in a bucket.
+    /// ## Example
+    /// ```rust
+    /// use minio_rsc::client::ListObjectVersionsArgs;
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let args = ListObjectVersionsArgs::default().prefix("photos/").max_keys(10);
+    /// let result = minio.list_object_versions("bucket", args).await?;
+    /// for version in result.versions {
+    ///     println!("{} {}", version.key, version.version_id);
+    /// }
+    /// # Ok(())}
+    /// ```
+    pub async fn list_object_versions<B>(
+        &self,
+        bucket: B,
+        args: ListObjectVersionsArgs,
+    ) -> Result<ListVersionsResult>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::GET)
+            .querys(args.args_query_map())
+            .headers_merge2(args.extra_headers)
+            .send_xml_ok()
+            .await
+    }
+
+    /// Lists all versions and delete markers of the objects in a bucket as a stream,
+    /// following `NextKeyMarker` and `NextVersionIdMarker` until the listing is complete.
+    /// ## Example
+    /// ```rust
+    /// use futures::StreamExt;
+    /// use minio_rsc::client::{ListObjectVersionsArgs, ListObjectVersionsItem};
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let args = ListObjectVersionsArgs::default().prefix("photos/");
+    /// let mut stream = minio.list_object_versions_stream("bucket", args);
+    /// while let Some(item) = stream.next().await {
+    ///     if let ListObjectVersionsItem::DeleteMarker(marker) = item? {
+    ///         println!("{} {}", marker.key, marker.version_id);
+    ///     }
+    /// }
+    /// # Ok(())}
+    /// ```
+    pub fn list_object_versions_stream<B>(
+        &self,
+        bucket: B,
+        mut args: ListObjectVersionsArgs,
+    ) -> Pin<Box<dyn Stream<Item = Result<ListObjectVersionsItem>> + Send>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let minio = self.clone();
+        Box::pin(stream! {
+            loop {
+                let result = minio.list_object_versions(bucket.clone(), args.clone()).await?;
+                for prefix in result.common_prefixes {
+                    yield Ok(ListObjectVersionsItem::CommonPrefix(prefix));
+                }
+                for version in result.versions {
+                    yield Ok(ListObjectVersionsItem::Version(version));
+                }
+                for marker in result.delete_markers {
+                    yield Ok(ListObjectVersionsItem::DeleteMarker(marker));
+                }
+                if !result.is_truncated || result.next_key_marker.is_empty() {
+                    break;
+                }
+                args.key_marker = Some(result.next_key_marker);
+                args.version_id_marker = if result.next_version_id_marker.is_empty() {
+                    None
+                } else {
+                    Some(result.next_version_id_marker)
+                };
+            }
+        })
+    }
+
     /// Create a bucket with object_lock
     /// ## params
     /// - object_lock: prevents objects from being deleted.
@@ -140,8 +284,10 @@ impl Minio {
         let bucket: BucketArgs = bucket.into();
         let region = &bucket.region.unwrap_or(self.region().to_string());
         let body = format!("<CreateBucketConfiguration><LocationConstraint>{}</LocationConstraint></CreateBucketConfiguration>",region);
-        self.executor(Method::PUT)
-            .bucket_name(bucket.name)
+        let res = self
+            .executor(Method::PUT)
+            .bucket_name(&bucket.name)
+            .region(region)
             .headers_merge2(bucket.extra_headers)
             .apply(|e| {
                 if object_lock {
@@ -152,16 +298,16 @@ impl Minio {
             })
             .body(body)
             .send_ok()
-            .await
-            .map(|res| {
-                let location = res.headers().get(header::LOCATION);
-                if let Some(loc) = location {
-                    if let Ok(loc) = loc.to_str() {
-                        return Ok(loc.to_string());
-                    }
-                }
-                Err(res.into())
-            })?
+            .await?;
+        // overwrite the fallback region cached while the bucket did not exist.
+        self._cache_region(&bucket.name, Some(region.to_string()));
+        let location = res.headers().get(header::LOCATION);
+        if let Some(loc) = location {
+            if let Ok(loc) = loc.to_str() {
+                return Ok(loc.to_string());
+            }
+        }
+        Err(res.into())
     }
 
     /// Remove an **empty** bucket.
@@ -180,10 +326,52 @@ impl Minio {
         B: Into<BucketArgs>,
     {
         let bucket: BucketArgs = bucket.into();
+        let name = bucket.name.clone();
         self._bucket_executor(bucket, Method::DELETE)
             .send_ok()
-            .await
-            .map(|_| ())
+            .await?;
+        self._cache_region(&name, None);
+        Ok(())
+    }
+
+    /// Get the region of a bucket by `GetBucketLocation`.
+    ///
+    /// Unlike the region used in requests, this always requests S3 service and bypasses the cache.
+    /// ## Example
+    /// ```rust
+    /// # use minio_rsc::Minio;
+    /// # async fn example(minio: Minio){
+    /// let region: String = minio.get_bucket_location("bucket").await.unwrap();
+    /// # }
+    /// ```
+    pub async fn get_bucket_location<B>(&self, bucket: B) -> Result<String>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let mut headers = bucket.extra_headers.unwrap_or_default();
+        if let Some(owner) = &bucket.expected_bucket_owner {
+            headers.insert("x-amz-expected-bucket-owner", owner.parse()?);
+        }
+        // executed directly, since the executor discovers the region by this method.
+        let res = self
+            ._execute(
+                Method::GET,
+                "us-east-1",
+                Some(bucket.name),
+                None,
+                Data::empty(),
+                Some(headers),
+                Some("location=".to_string()),
+            )
+            .await?;
+        let success = res.status().is_success();
+        let text = res.text().await?;
+        if !success {
+            let s: S3Error = text.as_str().try_into()?;
+            Err(s)?
+        }
+        Ok(parse_location_constraint(&text)?)
     }
 
     /// Get [Option]<[Tags]> of a bucket.
@@ -268,6 +456,509 @@ impl Minio {
         Ok(())
     }
 
+    /// Get [Option]<[PolicyDocument]> of a bucket.
+    /// Note: return [None] if bucket had not set policy or delete policy.
+    /// ## Example
+    /// ```rust
+    /// use minio_rsc::datatype::PolicyDocument;
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let policy: Option<PolicyDocument> = minio.get_bucket_policy("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn get_bucket_policy<B>(&self, bucket: B) -> Result<Option<PolicyDocument>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let res = self
+            ._bucket_executor(bucket, Method::GET)
+            .query("policy", "")
+            .send_text_ok()
+            .await;
+        match res {
+            Ok(text) => serde_json::from_str(&text)
+                .map(Some)
+                .map_err(|e| ValueError::new(e.to_string()).into()),
+            Err(Error::S3Error(s)) if s.code == "NoSuchBucketPolicy" => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Set policy of a bucket.
+    /// ## Example
+    /// ```rust
+    /// use minio_rsc::datatype::PolicyDocument;
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let policy = PolicyDocument::read_only("bucket", "public/");
+    /// minio.set_bucket_policy("bucket", policy).await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn set_bucket_policy<B>(&self, bucket: B, policy: PolicyDocument) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let body = serde_json::to_string(&policy).map_err(|e| ValueError::new(e.to_string()))?;
+        self._bucket_executor(bucket, Method::PUT)
+            .query("policy", "")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
+
+    /// Delete policy of a bucket.
+    /// ## Example
+    /// ```rust
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// minio.delete_bucket_policy("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn delete_bucket_policy<B>(&self, bucket: B) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::DELETE)
+            .query("policy", "")
+            .send_ok()
+            .await?;
+        Ok(())
+    }
+
+    /// Get [Option]<[LifecycleConfiguration]> of a bucket.
+    /// Note: return [None] if bucket had not set lifecycle or delete lifecycle.
+    /// ## Example
+    /// ```rust
+    /// use minio_rsc::datatype::LifecycleConfiguration;
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config: Option<LifecycleConfiguration> = minio.get_bucket_lifecycle("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn get_bucket_lifecycle<B>(&self, bucket: B) -> Result<Option<LifecycleConfiguration>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let res = self
+            ._bucket_executor(bucket, Method::GET)
+            .query("lifecycle", "")
+            .send_xml_ok()
+            .await;
+        match res {
+            Ok(config) => Ok(Some(config)),
+            Err(Error::S3Error(s)) if s.code == "NoSuchLifecycleConfiguration" => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Set [LifecycleConfiguration] of a bucket, replacing the existing rules.
+    /// ## Example
+    /// ```rust
+    /// use minio_rsc::datatype::{Expiration, Filter, LifecycleConfiguration, LifecycleRule};
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config = LifecycleConfiguration::new(vec![LifecycleRule::new("expire-logs")
+    ///     .filter(Filter::prefix("logs/"))
+    ///     .expiration(Expiration::days(30))]);
+    /// minio.set_bucket_lifecycle("bucket", config).await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn set_bucket_lifecycle<B>(
+        &self,
+        bucket: B,
+        config: LifecycleConfiguration,
+    ) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::PUT)
+            .query("lifecycle", "")
+            .xml(&config)
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
+
+    /// Delete lifecycle of a bucket.
+    /// ## Example
+    /// ```rust
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// minio.delete_bucket_lifecycle("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn delete_bucket_lifecycle<B>(&self, bucket: B) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::DELETE)
+            .query("lifecycle", "")
+            .send_ok()
+            .await?;
+        Ok(())
+    }
+
+    get_attr!(
+        get_bucket_notification,
+        "notification",
+        NotificationConfiguration
+    );
+
+    /// Set [NotificationConfiguration] of a bucket, replacing the existing configurations.
+    /// ## Example
+    /// ```rust
+    /// use minio_rsc::datatype::{NotificationConfiguration, QueueConfiguration};
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config = NotificationConfiguration {
+    ///     queue_configurations: vec![QueueConfiguration::new(
+    ///         "arn:minio:sqs::primary:webhook",
+    ///         vec!["s3:ObjectCreated:*".to_string()],
+    ///     )],
+    ///     ..Default::default()
+    /// };
+    /// minio.set_bucket_notification("bucket", config).await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn set_bucket_notification<B>(
+        &self,
+        bucket: B,
+        config: NotificationConfiguration,
+    ) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::PUT)
+            .query("notification", "")
+            .xml(&config)
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
+
+    /// Delete notification of a bucket, by setting an empty [NotificationConfiguration].
+    /// ## Example
+    /// ```rust
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// minio.delete_bucket_notification("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    #[inline]
+    pub async fn delete_bucket_notification<B>(&self, bucket: B) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        self.set_bucket_notification(bucket, NotificationConfiguration::default())
+            .await
+    }
+
+    /// Listen the events of objects in a bucket as a stream, a MinIO extension.
+    ///
+    /// Only events of objects with key `prefix` and `suffix` are received, set empty to disable.
+    /// The stream doesn't end until the connection is closed.
+    /// ## Example
+    /// ```rust
+    /// use futures::StreamExt;
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let events = vec!["s3:ObjectCreated:*".to_string()];
+    /// let mut stream = minio.listen_bucket_notification("bucket", "images/", ".jpg", events);
+    /// while let Some(record) = stream.next().await {
+    ///     let record = record?;
+    ///     println!("{} {}", record.event_name, record.s3.object.key);
+    /// }
+    /// # Ok(())}
+    /// ```
+    pub fn listen_bucket_notification<B>(
+        &self,
+        bucket: B,
+        prefix: &str,
+        suffix: &str,
+        mut events: Vec<String>,
+    ) -> Pin<Box<dyn Stream<Item = Result<NotificationRecord>> + Send>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let minio = self.clone();
+        let mut querys = QueryMap::new();
+        if !prefix.is_empty() {
+            querys.insert("prefix".to_string(), prefix.to_string());
+        }
+        if !suffix.is_empty() {
+            querys.insert("suffix".to_string(), suffix.to_string());
+        }
+        // repeated keys are sorted by value in the canonical request.
+        events.sort();
+        for event in events {
+            querys.insert("events".to_string(), event);
+        }
+        Box::pin(stream! {
+            let res = minio
+                ._bucket_executor(bucket, Method::GET)
+                .querys_merge(querys)
+                .send_ok()
+                .await?;
+            let mut body = res.bytes_stream();
+            let mut buf: Vec<u8> = vec![];
+            while let Some(data) = body.next().await {
+                buf.extend_from_slice(&data?);
+                while let Some(i) = buf.iter().position(|b| *b == b'\n') {
+                    let line: Vec<u8> = buf.drain(..=i).collect();
+                    for record in parse_notification_records(&line)? {
+                        yield Ok(record);
+                    }
+                }
+            }
+            for record in parse_notification_records(&buf)? {
+                yield Ok(record);
+            }
+        })
+    }
+
+    /// Get [Option]<[ServerSideEncryptionConfiguration]> of a bucket.
+    /// Note: return [None] if bucket had not set encryption or delete encryption.
+    /// ## Example
+    /// ```rust
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config = minio.get_bucket_encryption("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn get_bucket_encryption<B>(
+        &self,
+        bucket: B,
+    ) -> Result<Option<ServerSideEncryptionConfiguration>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let res = self
+            ._bucket_executor(bucket, Method::GET)
+            .query("encryption", "")
+            .send_xml_ok()
+            .await;
+        match res {
+            Ok(config) => Ok(Some(config)),
+            Err(Error::S3Error(s))
+                if s.code == "ServerSideEncryptionConfigurationNotFoundError" =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Set default [ServerSideEncryptionConfiguration] of a bucket.
+    /// ## Example
+    /// ```rust
+    /// use minio_rsc::datatype::ServerSideEncryptionConfiguration;
+    /// use minio_rsc::sse::SseS3;
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config = ServerSideEncryptionConfiguration::from(&SseS3::new());
+    /// minio.set_bucket_encryption("bucket", config).await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn set_bucket_encryption<B>(
+        &self,
+        bucket: B,
+        config: ServerSideEncryptionConfiguration,
+    ) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::PUT)
+            .query("encryption", "")
+            .xml(&config)
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
+
+    /// Delete default encryption of a bucket.
+    /// ## Example
+    /// ```rust
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// minio.delete_bucket_encryption("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn delete_bucket_encryption<B>(&self, bucket: B) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::DELETE)
+            .query("encryption", "")
+            .send_ok()
+            .await?;
+        Ok(())
+    }
+
+    /// Get [Option]<[ReplicationConfiguration]> of a bucket.
+    /// Note: return [None] if bucket had not set replication or delete replication.
+    /// ## Example
+    /// ```rust
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config = minio.get_bucket_replication("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn get_bucket_replication<B>(
+        &self,
+        bucket: B,
+    ) -> Result<Option<ReplicationConfiguration>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let res = self
+            ._bucket_executor(bucket, Method::GET)
+            .query("replication", "")
+            .send_xml_ok()
+            .await;
+        match res {
+            Ok(config) => Ok(Some(config)),
+            Err(Error::S3Error(s)) if s.code == "ReplicationConfigurationNotFoundError" => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Set [ReplicationConfiguration] of a bucket, replacing the existing rules.
+    ///
+    /// The bucket must be versioning enabled,
+    /// and the destination bucket ARN is created by the remote target of MinIO.
+    /// ## Example
+    /// ```rust
+    /// use minio_rsc::datatype::{ReplicationConfiguration, ReplicationRule};
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let arn = "arn:minio:replication::c5be6b16-769d-432a-9ef1-4567081f3566:dest";
+    /// let config = ReplicationConfiguration::new(vec![ReplicationRule::new("rule1", arn)]);
+    /// minio.set_bucket_replication("bucket", config).await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn set_bucket_replication<B>(
+        &self,
+        bucket: B,
+        config: ReplicationConfiguration,
+    ) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::PUT)
+            .query("replication", "")
+            .xml(&config)
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
+
+    /// Delete replication of a bucket.
+    /// ## Example
+    /// ```rust
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// minio.delete_bucket_replication("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn delete_bucket_replication<B>(&self, bucket: B) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::DELETE)
+            .query("replication", "")
+            .send_ok()
+            .await?;
+        Ok(())
+    }
+
+    /// Get [Option]<[CorsConfiguration]> of a bucket.
+    /// Note: return [None] if bucket had not set cors or delete cors.
+    /// ## Example
+    /// ```rust
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config = minio.get_bucket_cors("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn get_bucket_cors<B>(&self, bucket: B) -> Result<Option<CorsConfiguration>>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let res = self
+            ._bucket_executor(bucket, Method::GET)
+            .query("cors", "")
+            .send_xml_ok()
+            .await;
+        match res {
+            Ok(config) => Ok(Some(config)),
+            Err(Error::S3Error(s)) if s.code == "NoSuchCORSConfiguration" => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Set [CorsConfiguration] of a bucket, replacing the existing rules.
+    /// ## Example
+    /// ```rust
+    /// use minio_rsc::datatype::{CorsConfiguration, CorsRule};
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// let config = CorsConfiguration::new(vec![CorsRule::new(
+    ///     vec!["https://example.com".to_string()],
+    ///     vec!["PUT".to_string()],
+    /// )]);
+    /// minio.set_bucket_cors("bucket", config).await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn set_bucket_cors<B>(&self, bucket: B, config: CorsConfiguration) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::PUT)
+            .query("cors", "")
+            .xml(&config)
+            .send_ok()
+            .await
+            .map(|_| ())
+    }
+
+    /// Delete cors of a bucket.
+    /// ## Example
+    /// ```rust
+    /// # use minio_rsc::{Minio, error::Result};
+    /// # async fn example(minio: Minio) -> Result<()> {
+    /// minio.delete_bucket_cors("bucket").await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn delete_bucket_cors<B>(&self, bucket: B) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        self._bucket_executor(bucket, Method::DELETE)
+            .query("cors", "")
+            .send_ok()
+            .await?;
+        Ok(())
+    }
+
     get_attr!(get_bucket_versioning, "versioning", VersioningConfiguration);
 
     /// Set [VersioningConfiguration] of a bucket.
@@ -343,3 +1034,21 @@ impl Minio {
         self.set_object_lock_config(bucket, config).await
     }
 }
+
+/// Parse a line of `listen_bucket_notification`, the keep-alive blank line has no record.
+fn parse_notification_records(
+    line: &[u8],
+) -> std::result::Result<Vec<NotificationRecord>, ValueError> {
+    #[derive(Deserialize)]
+    struct Records {
+        #[serde(rename = "Records", default)]
+        records: Vec<NotificationRecord>,
+    }
+
+    if line.iter().all(u8::is_ascii_whitespace) {
+        return Ok(vec![]);
+    }
+    serde_json::from_slice::<Records>(line)
+        .map(|r| r.records)
+        .map_err(|e| ValueError::new(e.to_string()))
+}
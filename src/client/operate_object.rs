@@ -1,18 +1,20 @@
-use std::collections::HashMap;
-use std::ops::Add;
 use std::path::Path;
 use std::pin::Pin;
 
-use bytes::{Bytes, BytesMut};
+use async_stream::stream;
+use bytes::Bytes;
 use futures::{Stream, StreamExt};
 use hyper::{header, HeaderMap, Method};
-use reqwest::Response;
 
+use super::response::parse_user_metadata;
 use super::{BucketArgs, CopySource, KeyArgs, ObjectStat, SelectObjectReader, Tags};
+use super::{GetObjectOutput, RemoveObjectsArgs, RemoveObjectsItem};
+use crate::datatype::{Delete, DeleteResult, ObjectIdentifier};
 use crate::datatype::{LegalHold, Retention};
 use crate::datatype::{LegalHoldStatus, SelectRequest};
-use crate::error::{Error, Result, S3Error, ValueError};
-use crate::signer::{MAX_MULTIPART_OBJECT_SIZE, MIN_PART_SIZE};
+use crate::error::{Error, Result, ValueError};
+use crate::signer::{MAX_DELETE_OBJECTS, MAX_MULTIPART_COUNT, MAX_MULTIPART_OBJECT_SIZE};
+use crate::signer::{MAX_PART_SIZE, MIN_PART_SIZE};
 use crate::Minio;
 
 /// Operating the object
@@ -25,6 +27,7 @@ impl Minio {
         key: KeyArgs,
         with_sscs: bool,
         with_content_type: bool,
+        with_conditions: bool,
     ) -> Result<super::BaseExecutor> {
         let is_put = method == Method::PUT;
         let metadata_header = if is_put {
@@ -32,9 +35,16 @@ impl Minio {
         } else {
             HeaderMap::new()
         };
+        // the preconditions are only valid in `get_object`, `stat_object` and `put_object`.
+        let condition_header = if with_conditions {
+            key.get_condition_header()?
+        } else {
+            HeaderMap::new()
+        };
         let executor = self
             ._bucket_executor(bucket, method)
             .object_name(&key.name)
+            .headers_merge(condition_header)
             .headers_merge2(key.extra_headers)
             .apply(|mut e| {
                 if let Some(version_id) = &key.version_id {
@@ -61,6 +71,11 @@ impl Minio {
     }
 
     /// Creates a copy of an object that is already stored in Minio.
+    ///
+    /// The source is read by `stat_object` first, sources larger than 5GiB are copied
+    /// by multipart upload like [compose_object](Self::compose_object).
+    /// Unless `metadata_replace` is set, the content type, user metadata and storage class
+    /// of the source are kept in that case, but the tags are not copied.
     /// ## Exapmle
     /// ``` rust
     /// # use minio_rsc::Minio;
@@ -83,15 +98,185 @@ impl Minio {
         K: Into<KeyArgs>,
     {
         let bucket: BucketArgs = bucket.into();
-        let key: KeyArgs = key.into();
-        self._object_executor(Method::PUT, bucket, key, true, true)?
+        let mut key: KeyArgs = key.into();
+        if let Some(stat) = self._stat_copy_source(&src).await? {
+            if stat.size() > MAX_PART_SIZE {
+                if !src.metadata_replace {
+                    key.content_type = Some(stat.content_type().to_string());
+                    key.metadata = stat.metadata().clone();
+                    if let Some(storage_class) = stat.storage_class() {
+                        let mut headers = key.extra_headers.unwrap_or_default();
+                        if !headers.contains_key("x-amz-storage-class") {
+                            headers.insert("x-amz-storage-class", storage_class.parse()?);
+                        }
+                        key.extra_headers = Some(headers);
+                    }
+                }
+                return self._compose_object(bucket, key, vec![(src, stat)]).await;
+            }
+        }
+        self._copy_object(bucket, key, src).await
+    }
+
+    async fn _copy_object(&self, bucket: BucketArgs, key: KeyArgs, src: CopySource) -> Result<()> {
+        self._object_executor(Method::PUT, bucket, key, true, true, false)?
             .headers_merge(src.args_headers())
             .send_ok()
             .await?;
         Ok(())
     }
 
+    /// Get information of the source object, `None` if not exists.
+    async fn _stat_copy_source(&self, src: &CopySource) -> Result<Option<ObjectStat>> {
+        let bucket = BucketArgs::new(&src.bucket_name).region(src.region.clone());
+        let key = KeyArgs::new(&src.object_name)
+            .version_id(src.version_id.clone())
+            .extra_headers(src.ssec_read_headers());
+        self.stat_object(bucket, key).await
+    }
+
+    /// Creates an object by concatenating `sources` on the server side, through `upload_part_copy`.
+    ///
+    /// Each source may be a range of an object set by [CopySource::range].
+    /// Sources larger than 5GiB are split into multiple parts.
+    /// Every source must not be empty, and except the last must be at least 5MiB,
+    /// and the result must be at most 5TiB in at most 10000 parts.
+    ///
+    /// The metadata, tags and storage class of the sources are not copied, set them by `key`.
+    /// The sources are checked by `If-Match` with the ETag read before copying,
+    /// unless `match_etag` of the source is set.
+    /// ## Exapmle
+    /// ``` rust
+    /// # use minio_rsc::Minio;
+    /// use minio_rsc::error::Result;
+    /// use minio_rsc::client::CopySource;
+    ///
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let sources = vec![
+    ///     CopySource::new("bucket", "part1"),
+    ///     CopySource::new("bucket", "part2").range(0, 1024),
+    /// ];
+    /// minio.compose_object("bucket", "composed", sources).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn compose_object<B, K>(
+        &self,
+        bucket: B,
+        key: K,
+        sources: Vec<CopySource>,
+    ) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let key: KeyArgs = key.into();
+        let mut stated = Vec::with_capacity(sources.len());
+        for src in sources {
+            match self._stat_copy_source(&src).await? {
+                Some(stat) => stated.push((src, stat)),
+                None => {
+                    let name = format!("{}/{}", src.bucket_name, src.object_name);
+                    return Err(ValueError::new(format!("source {} does not exist", name)).into());
+                }
+            }
+        }
+        self._compose_object(bucket, key, stated).await
+    }
+
+    /// Compose the `sources` with their [ObjectStat] read before.
+    async fn _compose_object(
+        &self,
+        bucket: BucketArgs,
+        key: KeyArgs,
+        sources: Vec<(CopySource, ObjectStat)>,
+    ) -> Result<()> {
+        if sources.is_empty() {
+            return Err(ValueError::from("compose_object requires at least one source").into());
+        }
+        let count = sources.len();
+        let mut total_size = 0;
+        let mut parts: Vec<CopySource> = Vec::new();
+        for (index, (src, stat)) in sources.into_iter().enumerate() {
+            let name = format!("{}/{}", src.bucket_name, src.object_name);
+            let size = stat.size();
+            if src.offset > size || src.offset + src.length > size {
+                return Err(ValueError::new(format!(
+                    "range of source {} exceeds the object size {}",
+                    name, size
+                ))
+                .into());
+            }
+            let length = if src.length > 0 {
+                src.length
+            } else {
+                size - src.offset
+            };
+            if length == 0 {
+                return Err(ValueError::new(format!("source {} is empty", name)).into());
+            }
+            let is_last = index + 1 == count;
+            if length < MIN_PART_SIZE && !is_last {
+                return Err(ValueError::new(format!(
+                    "source {} is {} bytes, only the last source can be smaller than 5MiB",
+                    name, length
+                ))
+                .into());
+            }
+            total_size += length;
+            if total_size > MAX_MULTIPART_OBJECT_SIZE {
+                return Err(ValueError::from("max object size is 5TiB").into());
+            }
+            let src = match src.match_etag {
+                Some(_) => src,
+                None => src.match_etag(Some(stat.etag().to_string())),
+            };
+            if count == 1 && length == size && size <= MAX_PART_SIZE {
+                return self._copy_object(bucket, key, src.range(0, 0)).await;
+            }
+            // split the source evenly into parts no larger than 5GiB.
+            let split = length.div_ceil(MAX_PART_SIZE);
+            let mut offset = src.offset;
+            for i in 0..split {
+                let part_size = length / split + usize::from(i < length % split);
+                parts.push(src.clone().range(offset, part_size));
+                offset += part_size;
+            }
+        }
+        if parts.len() > MAX_MULTIPART_COUNT {
+            return Err(ValueError::from("compose_object supports at most 10000 parts").into());
+        }
+
+        let task = self.create_multipart_upload(bucket, key).await?;
+        let mut uploaded = Vec::with_capacity(parts.len());
+        for (index, src) in parts.into_iter().enumerate() {
+            match self.upload_part_copy(&task, index + 1, src).await {
+                Ok(part) => uploaded.push(part),
+                Err(e) => {
+                    return match self.abort_multipart_upload(&task).await {
+                        Ok(_) => Err(e),
+                        Err(err) => Err(err),
+                    }
+                }
+            }
+        }
+        self.complete_multipart_upload(&task, uploaded, None)
+            .await
+            .map(|_| ())
+    }
+
     /// Downloads data of an object to file.
+    ///
+    /// The object is downloaded in ranges concurrently into a `.part` file next to `path`,
+    /// see [DownloadConfig](crate::client::DownloadConfig).
+    /// Every range request carries `If-Match` with the ETag of the object,
+    /// so the download fails if the object is modified meanwhile.
+    /// After verifying the size and, for single-part unencrypted objects, the MD5 checksum,
+    /// the `.part` file is renamed to `path`.
+    ///
+    /// The progress is saved in a `.part.json` file, so an interrupted download
+    /// is resumed by calling again, as long as the ETag of the object is unchanged.
     /// # Exapmle
     /// ``` rust
     /// # use minio_rsc::Minio;
@@ -108,49 +293,120 @@ impl Minio {
         K: Into<KeyArgs>,
         P: AsRef<Path>,
     {
-        use tokio::{fs::File, io::AsyncWriteExt};
+        use super::download::{file_md5, with_suffix, write_range, DownloadState};
+        use futures::stream::FuturesUnordered;
 
-        let res = self.get_object(bucket, key).await?;
-        if !res.status().is_success() {
-            let text = res.text().await?;
-            let s3err: S3Error = text.as_str().try_into()?;
-            Err(s3err)?
-        } else {
-            let mut stream = res.bytes_stream();
-            let mut file = File::create(path).await?;
-            while let Some(item) = stream.next().await {
-                if let Ok(datas) = item {
-                    file.write_all(&datas).await?;
+        let bucket: BucketArgs = bucket.into();
+        let key: KeyArgs = key.into();
+        let path = path.as_ref();
+        let stat = match self.stat_object(bucket.clone(), key.clone()).await? {
+            Some(stat) => stat,
+            None => {
+                // HEAD responses have no body, get the error response of the object.
+                self.get_object(bucket, key).await?;
+                return Err(ValueError::from("failed to stat the object").into());
+            }
+        };
+        let size = stat.size();
+        let etag = stat.etag();
+        let part_size = self.download_config().part_size;
+        let concurrency = self.download_config().max_concurrency;
+        let part_file = with_suffix(path, ".part");
+        let state_file = with_suffix(path, ".part.json");
+
+        let mut state = match DownloadState::load(&state_file).await {
+            Some(state)
+                if state.etag == etag
+                    && state.size == size
+                    && state.part_size == part_size
+                    && tokio::fs::metadata(&part_file).await.is_ok() =>
+            {
+                state
+            }
+            _ => {
+                let file = tokio::fs::File::create(&part_file).await?;
+                file.set_len(size as u64).await?;
+                DownloadState::new(etag, size, part_size)
+            }
+        };
+        state.save(&state_file).await?;
+
+        let done = state.done.clone();
+        let mut downloading = FuturesUnordered::new();
+        for index in (0..size.div_ceil(part_size)).filter(|i| !done.contains(i)) {
+            if downloading.len() >= concurrency {
+                if let Some(index) = downloading.next().await {
+                    state.done.push(index?);
+                    state.save(&state_file).await?;
                 }
             }
-            Ok(())
+            let offset = index * part_size;
+            let length = part_size.min(size - offset);
+            let key = key.clone().offset(offset).length(length);
+            let range = key.range().unwrap_or_default();
+            let executor = self
+                ._object_executor(Method::GET, bucket.clone(), key, true, false, false)?
+                .header(header::RANGE, &range)
+                .header(header::IF_MATCH, &format!("\"{}\"", etag));
+            let part_file = &part_file;
+            downloading.push(async move {
+                let res = executor.send_ok().await?;
+                write_range(part_file, offset, length, res).await?;
+                Ok::<_, Error>(index)
+            });
+        }
+        while let Some(index) = downloading.next().await {
+            state.done.push(index?);
+            state.save(&state_file).await?;
+        }
+
+        let mut verified = tokio::fs::metadata(&part_file).await?.len() == size as u64;
+        // the ETag of an encrypted object is not the MD5 of its data.
+        let is_md5 = etag.len() == 32
+            && etag.chars().all(|c| c.is_ascii_hexdigit())
+            && stat.server_side_encryption().is_none()
+            && key.ssec_headers.is_none();
+        if verified && is_md5 {
+            verified = file_md5(&part_file).await?.eq_ignore_ascii_case(etag);
+        }
+        if !verified {
+            tokio::fs::remove_file(&part_file).await.ok();
+            tokio::fs::remove_file(&state_file).await.ok();
+            return Err(ValueError::from("downloaded data does not match the object").into());
         }
+        tokio::fs::rename(&part_file, path).await?;
+        tokio::fs::remove_file(&state_file).await.ok();
+        Ok(())
     }
 
-    /// Get [reqwest::Response] of an object.
+    /// Get data and metadata of an object.
     /// ## Exapmle
     /// ``` rust
-    /// use reqwest::Response;
+    /// use minio_rsc::client::GetObjectOutput;
     /// # use minio_rsc::Minio;
     /// # use minio_rsc::client::KeyArgs;
     /// # use minio_rsc::error::Result;
     /// # async fn example(minio: Minio)->Result<()>{
-    /// let response: Response = minio.get_object("bucket", "file.txt").await?;
+    /// let output: GetObjectOutput = minio.get_object("bucket", "file.txt").await?;
+    /// println!("{} {}", output.etag(), output.content_length());
+    /// let data = output.bytes().await?;
     /// let key = KeyArgs::new("file.txt").version_id(Some("cdabf31a-9752-4265-b137-6b3961fbaf9b".to_string()));
-    /// let response: Response = minio.get_object("bucket", key).await?;
+    /// let output: GetObjectOutput = minio.get_object("bucket", key).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_object<B, K>(&self, bucket: B, key: K) -> Result<Response>
+    pub async fn get_object<B, K>(&self, bucket: B, key: K) -> Result<GetObjectOutput>
     where
         B: Into<BucketArgs>,
         K: Into<KeyArgs>,
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
+        let bucket_name = bucket.name.clone();
+        let object_name = key.name.clone();
         let range = key.range();
-        Ok(self
-            ._object_executor(Method::GET, bucket, key, true, true)?
+        let res = self
+            ._object_executor(Method::GET, bucket, key, true, true, true)?
             .apply(|e| {
                 if let Some(range) = range {
                     e.header(header::RANGE, &range)
@@ -159,7 +415,8 @@ impl Minio {
                 }
             })
             .send_ok()
-            .await?)
+            .await?;
+        Ok(GetObjectOutput::new(bucket_name, object_name, res))
     }
 
     /// Uploads data to an object in a bucket.
@@ -190,7 +447,7 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        self._object_executor(Method::PUT, bucket, key, true, true)?
+        self._object_executor(Method::PUT, bucket, key, true, true, true)?
             .body(data)
             .send_ok()
             .await?;
@@ -200,13 +457,16 @@ impl Minio {
     /// Upload large payload in an efficient manner easily.
     ///
     /// - len: total byte length of stream.
-    /// If set None, the data will be transmitted through `multipart_upload`.
-    /// otherwise the data will be transmitted in multiple chunks through an HTTP request.
+    ///   If set None, or at least 5MiB when `multi_chunked_encoding` is disabled,
+    ///   the data will be transmitted through `multipart_upload`,
+    ///   with parts uploaded concurrently, see [UploadConfig](crate::client::UploadConfig).
+    ///   Otherwise the data will be transmitted through a single HTTP request,
+    ///   in multiple chunks if `multi_chunked_encoding` is enabled.
     pub async fn put_object_stream<B, K>(
         &self,
         bucket: B,
         key: K,
-        mut stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>,
+        stream: Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>,
         len: Option<usize>,
     ) -> Result<()>
     where
@@ -215,66 +475,37 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
+        let config = self.upload_config();
+        let part_size = config.part_size_for(len);
         if let Some(len) = len {
             if len >= MAX_MULTIPART_OBJECT_SIZE {
                 return Err(ValueError::from("max object size is 5TiB").into());
             }
             if self.multi_chunked() || len < MIN_PART_SIZE {
-                self._object_executor(Method::PUT, bucket, key, true, true)?
+                self._object_executor(Method::PUT, bucket, key, true, true, true)?
                     .body((stream, len))
                     .send_ok()
                     .await?;
                 return Ok(());
             }
         }
+        // the preconditions of writing are checked when completing the upload.
+        let condition_header = key.get_condition_header()?;
         let mpu_args = self.create_multipart_upload(bucket, key).await?;
-
-        let mut parts = Vec::new();
-        let mut current = BytesMut::with_capacity(MIN_PART_SIZE);
-        while let Some(piece) = stream.next().await {
-            if current.len() >= MIN_PART_SIZE {
-                let part = match self
-                    .upload_part(&mpu_args, parts.len().add(1), current.freeze())
-                    .await
-                {
-                    Ok(pce) => pce,
-                    Err(e) => {
-                        return match self.abort_multipart_upload(&mpu_args).await {
-                            Ok(_) => Err(e),
-                            Err(err) => Err(err),
-                        }
-                    }
-                };
-                current = BytesMut::with_capacity(MIN_PART_SIZE);
-                parts.push(part);
-            }
-            match piece {
-                Ok(open_piece) => {
-                    current.extend_from_slice(&open_piece);
-                }
-                Err(e) => {
-                    self.abort_multipart_upload(&mpu_args).await?;
-                    return Err(e);
+        let concurrency = config.concurrency(part_size);
+        let parts = match self
+            ._upload_parts(&mpu_args, stream, part_size, concurrency)
+            .await
+        {
+            Ok(parts) => parts,
+            Err(e) => {
+                return match self.abort_multipart_upload(&mpu_args).await {
+                    Ok(_) => Err(e),
+                    Err(err) => Err(err),
                 }
             }
-        }
-        if current.len() != 0 {
-            let part = match self
-                .upload_part(&mpu_args, parts.len().add(1), current.freeze())
-                .await
-            {
-                Ok(pce) => pce,
-                Err(e) => {
-                    return match self.abort_multipart_upload(&mpu_args).await {
-                        Ok(_) => Err(e),
-                        Err(err) => Err(err),
-                    }
-                }
-            };
-            parts.push(part);
-        }
-
-        self.complete_multipart_upload(&mpu_args, parts, None)
+        };
+        self.complete_multipart_upload(&mpu_args, parts, Some(condition_header))
             .await
             .map(|_| ())
     }
@@ -298,6 +529,7 @@ impl Minio {
     {
         use crate::signer::RECOMMEND_CHUNK_SIZE;
         use async_stream::stream;
+        use bytes::BytesMut;
         use tokio::io::AsyncReadExt;
 
         let mut file = tokio::fs::File::open(path).await?;
@@ -317,6 +549,163 @@ impl Minio {
         self.put_object_stream(bucket, key, stm, Some(len)).await
     }
 
+    /// Uploads data from a file to an object in a bucket, resuming from the checkpoint in `store`.
+    ///
+    /// The multipart upload and its completed parts are recorded in `store`.
+    /// If the upload fails, the multipart upload is kept and calling again with the same
+    /// bucket, key, path and store only uploads the missing parts.
+    /// Parts of the checkpoint are reused only if `list_parts` reports the same ETag.
+    /// The checkpoint is discarded if the file has been modified.
+    ///
+    /// Files not larger than the part size are uploaded by `fput_object`.
+    /// ## Exapmle
+    /// ``` rust
+    /// # use minio_rsc::Minio;
+    /// # use minio_rsc::error::Result;
+    /// use minio_rsc::client::FileCheckpointStore;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let store = FileCheckpointStore::new(".minio-checkpoints");
+    /// minio.fput_object_resumable("bucket", "file.txt", "localfile.txt", &store).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "fs-tokio")]
+    pub async fn fput_object_resumable<B, K, P, S>(
+        &self,
+        bucket: B,
+        key: K,
+        path: P,
+        store: &S,
+    ) -> Result<()>
+    where
+        B: Into<BucketArgs>,
+        K: Into<KeyArgs>,
+        P: AsRef<Path>,
+        S: super::CheckpointStore + ?Sized,
+    {
+        use super::UploadCheckpoint;
+        use futures::stream::FuturesUnordered;
+        use std::time::UNIX_EPOCH;
+
+        let bucket: BucketArgs = bucket.into();
+        let key: KeyArgs = key.into();
+        let path = path.as_ref();
+        let meta = tokio::fs::metadata(path).await?;
+        let size = meta.len();
+        let modified = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_millis() as i64);
+        let config = self.upload_config();
+        if size as usize <= config.part_size_for(Some(size as usize)) {
+            return self.fput_object(bucket, key, path).await;
+        }
+        if size as usize >= MAX_MULTIPART_OBJECT_SIZE {
+            return Err(ValueError::from("max object size is 5TiB").into());
+        }
+
+        let id = UploadCheckpoint::id(
+            &bucket.name,
+            &key.name,
+            &tokio::fs::canonicalize(path).await?.to_string_lossy(),
+        );
+        let ssec_headers = key.ssec_headers.clone();
+        let condition_header = key.get_condition_header()?;
+        let checkpoint = match store.load(&id).await? {
+            Some(checkpoint) => {
+                let mut task = checkpoint.task();
+                task.set_ssec_header(ssec_headers.clone());
+                if checkpoint.size != size || checkpoint.modified != modified {
+                    self.abort_multipart_upload(&task).await.ok();
+                    None
+                } else {
+                    self._reconcile_checkpoint(&task, checkpoint).await?
+                }
+            }
+            None => None,
+        };
+        let mut checkpoint = match checkpoint {
+            Some(checkpoint) => checkpoint,
+            None => {
+                let part_size = config.part_size_for(Some(size as usize));
+                let task = self.create_multipart_upload(bucket, key).await?;
+                let checkpoint = UploadCheckpoint::new(&task, size, modified, part_size);
+                store.save(&id, &checkpoint).await?;
+                checkpoint
+            }
+        };
+        let mut task = checkpoint.task();
+        task.set_ssec_header(ssec_headers);
+
+        let part_size = checkpoint.part_size;
+        let concurrency = config.concurrency(part_size);
+        let part_count = (size as usize).div_ceil(part_size);
+        let uploaded: Vec<usize> = checkpoint.parts.iter().map(|p| p.part_number).collect();
+        let mut uploading = FuturesUnordered::new();
+        for part_number in 1..=part_count {
+            if uploaded.contains(&part_number) {
+                continue;
+            }
+            if uploading.len() >= concurrency {
+                if let Some(part) = uploading.next().await {
+                    checkpoint.parts.push(part?);
+                    store.save(&id, &checkpoint).await?;
+                }
+            }
+            let task = &task;
+            uploading.push(async move {
+                let offset = ((part_number - 1) * part_size) as u64;
+                let data = read_file_part(path, offset, part_size).await?;
+                self.upload_part(task, part_number, data).await
+            });
+        }
+        while let Some(part) = uploading.next().await {
+            checkpoint.parts.push(part?);
+            store.save(&id, &checkpoint).await?;
+        }
+
+        let mut parts = checkpoint.parts;
+        parts.sort_by_key(|p| p.part_number);
+        self.complete_multipart_upload(&task, parts, Some(condition_header))
+            .await?;
+        store.remove(&id).await?;
+        Ok(())
+    }
+
+    /// Keep the parts of the checkpoint which `list_parts` reports with the same ETag.
+    ///
+    /// Returns `None` if the multipart upload no longer exists.
+    #[cfg(feature = "fs-tokio")]
+    async fn _reconcile_checkpoint(
+        &self,
+        task: &super::MultipartUploadTask,
+        mut checkpoint: super::UploadCheckpoint,
+    ) -> Result<Option<super::UploadCheckpoint>> {
+        let mut uploaded = std::collections::HashMap::new();
+        let mut marker = None;
+        loop {
+            let result = match self.list_parts(task, None, marker).await {
+                Ok(result) => result,
+                Err(Error::S3Error(e)) if e.code == "NoSuchUpload" => return Ok(None),
+                Err(e) => return Err(e),
+            };
+            for part in result.parts {
+                uploaded.insert(part.part_number, part.e_tag);
+            }
+            if !result.is_truncated {
+                break;
+            }
+            marker = Some(result.next_part_number_marker);
+        }
+        checkpoint.parts.retain(|p| {
+            uploaded
+                .get(&p.part_number)
+                .is_some_and(|e| e.trim_matches('"') == p.e_tag.trim_matches('"'))
+        });
+        Ok(Some(checkpoint))
+    }
+
     /// Remove an object.
     /// ## Exapmle
     /// ``` rust
@@ -334,15 +723,90 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        self._object_executor(Method::DELETE, bucket, key, true, false)?
+        self._object_executor(Method::DELETE, bucket, key, true, false, false)?
             .send_ok()
             .await?;
         Ok(())
     }
 
+    /// Remove multiple objects, batched into `DeleteObjects` requests of up to 1000 keys.
+    ///
+    /// Returns a stream of per-key results, see [RemoveObjectsItem].
+    /// The request parameters are set by [RemoveObjectsArgs].
+    /// ## Exapmle
+    /// ``` rust
+    /// use futures::{stream, StreamExt};
+    /// use minio_rsc::client::{RemoveObjectsArgs, RemoveObjectsItem};
+    /// # use minio_rsc::Minio;
+    /// # use minio_rsc::error::Result;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let keys = vec!["file1.txt", "file2.txt"];
+    /// let args = RemoveObjectsArgs::default().quiet(true);
+    /// let mut results = minio.remove_objects("bucket", stream::iter(keys), args);
+    /// while let Some(item) = results.next().await {
+    ///     if let RemoveObjectsItem::Error(err) = item? {
+    ///         println!("failed to remove {}: {}", err.key, err.message);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_objects<B, S, K>(
+        &self,
+        bucket: B,
+        objects: S,
+        args: RemoveObjectsArgs,
+    ) -> Pin<Box<dyn Stream<Item = Result<RemoveObjectsItem>> + Send>>
+    where
+        B: Into<BucketArgs>,
+        S: Stream<Item = K> + Send + 'static,
+        K: Into<KeyArgs> + 'static,
+    {
+        let bucket: BucketArgs = bucket.into();
+        let minio = self.clone();
+        let mut chunks = Box::pin(objects.map(Into::into).chunks(MAX_DELETE_OBJECTS));
+        Box::pin(stream! {
+            while let Some(keys) = chunks.next().await {
+                let objects = keys
+                    .into_iter()
+                    .map(|key: KeyArgs| ObjectIdentifier {
+                        key: key.name,
+                        version_id: key.version_id,
+                    })
+                    .collect();
+                let delete = Delete {
+                    quiet: args.quiet,
+                    objects,
+                };
+                let result = minio
+                    ._bucket_executor(bucket.clone(), Method::POST)
+                    .query("delete", "")
+                    .apply(|e| {
+                        if args.bypass_governance {
+                            e.header("x-amz-bypass-governance-retention", "true")
+                        } else {
+                            e
+                        }
+                    })
+                    .xml(&delete)
+                    .send_xml_ok::<DeleteResult>()
+                    .await?;
+                for deleted in result.deleted {
+                    yield Ok(RemoveObjectsItem::Deleted(deleted));
+                }
+                for error in result.errors {
+                    yield Ok(RemoveObjectsItem::Error(error));
+                }
+            }
+        })
+    }
+
     /// Get object information.
     ///
     /// return Ok([Some]) if object exists and you have READ access to the object, otherwise return Ok([None])
+    ///
+    /// Returns [Error::NotModified] or [Error::PreconditionFailed]
+    /// if the preconditions of [KeyArgs] are not met.
     /// ## Exapmle
     /// ``` rust
     /// # use minio_rsc::Minio;
@@ -362,10 +826,14 @@ impl Minio {
         let bucket_name = bucket.name.clone();
         let object_name = key.name.clone();
         let res = self
-            ._object_executor(Method::HEAD, bucket, key, true, false)?
+            ._object_executor(Method::HEAD, bucket, key, true, false, true)?
             .send()
             .await?;
-        if !res.status().is_success() {
+        if res.status() == 304 {
+            return Err(Error::NotModified);
+        } else if res.status() == 412 {
+            return Err(Error::PreconditionFailed);
+        } else if !res.status().is_success() {
             return Ok(None);
         }
         let res_header = res.headers();
@@ -393,15 +861,15 @@ impl Minio {
             .map(|x| x.to_str().unwrap_or(""))
             .unwrap_or("")
             .to_owned();
-        let mut metadata = HashMap::new();
-        res_header.into_iter().for_each(|(k, v)| {
-            let key = k.as_str();
-            if key.starts_with("x-amz-meta-") {
-                if let Ok(value) = String::from_utf8(v.as_bytes().to_vec()) {
-                    metadata.insert(key[11..].to_string(), value.to_owned());
-                }
-            }
-        });
+        let storage_class = res_header
+            .get("x-amz-storage-class")
+            .and_then(|x| x.to_str().ok())
+            .map(ToString::to_string);
+        let server_side_encryption = res_header
+            .get("x-amz-server-side-encryption")
+            .and_then(|x| x.to_str().ok())
+            .map(ToString::to_string);
+        let metadata = parse_user_metadata(res_header);
         Ok(Some(ObjectStat {
             bucket_name,
             object_name,
@@ -410,6 +878,8 @@ impl Minio {
             content_type,
             version_id,
             size,
+            storage_class,
+            server_side_encryption,
             metadata,
         }))
     }
@@ -423,7 +893,7 @@ impl Minio {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
         let result = self
-            ._object_executor(Method::GET, bucket, key, false, false)?
+            ._object_executor(Method::GET, bucket, key, false, false, false)?
             .query("legal-hold", "")
             .send_xml_ok::<LegalHold>()
             .await;
@@ -452,7 +922,7 @@ impl Minio {
         let legal_hold: LegalHold = LegalHold {
             status: LegalHoldStatus::ON,
         };
-        self._object_executor(Method::PUT, bucket, key, false, false)?
+        self._object_executor(Method::PUT, bucket, key, false, false, false)?
             .query("legal-hold", "")
             .xml(&legal_hold)
             .send_ok()
@@ -471,7 +941,7 @@ impl Minio {
         let legal_hold: LegalHold = LegalHold {
             status: LegalHoldStatus::OFF,
         };
-        self._object_executor(Method::PUT, bucket, key, false, false)?
+        self._object_executor(Method::PUT, bucket, key, false, false, false)?
             .query("legal-hold", "")
             .xml(&legal_hold)
             .send_ok()
@@ -498,7 +968,7 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        self._object_executor(Method::GET, bucket, key, false, false)?
+        self._object_executor(Method::GET, bucket, key, false, false, false)?
             .query("tagging", "")
             .send_xml_ok()
             .await
@@ -527,7 +997,7 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        self._object_executor(Method::PUT, bucket, key, false, false)?
+        self._object_executor(Method::PUT, bucket, key, false, false, false)?
             .query("tagging", "")
             .xml(&tags.into())
             .send_ok()
@@ -552,7 +1022,7 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        self._object_executor(Method::DELETE, bucket, key, false, false)?
+        self._object_executor(Method::DELETE, bucket, key, false, false, false)?
             .query("tagging", "")
             .send_ok()
             .await
@@ -567,7 +1037,7 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        self._object_executor(Method::GET, bucket, key, false, false)?
+        self._object_executor(Method::GET, bucket, key, false, false, false)?
             .query("retention", "")
             .send_xml_ok()
             .await
@@ -586,7 +1056,7 @@ impl Minio {
     {
         let bucket: BucketArgs = bucket.into();
         let key: KeyArgs = key.into();
-        self._object_executor(Method::PUT, bucket, key, false, false)?
+        self._object_executor(Method::PUT, bucket, key, false, false, false)?
             .query("retention", "")
             .xml(&retention)
             .send_ok()
@@ -629,7 +1099,7 @@ impl Minio {
         let key: KeyArgs = key.into();
         let body = request.to_xml();
         let res = self
-            ._object_executor(Method::POST, bucket, key, true, false)?
+            ._object_executor(Method::POST, bucket, key, true, false, false)?
             .query_string("select&select-type=2")
             .body(body)
             .send_ok()
@@ -637,3 +1107,15 @@ impl Minio {
         Ok(SelectObjectReader::new(res))
     }
 }
+
+/// Read `size` bytes of the file at `offset`, or less at the end of the file.
+#[cfg(feature = "fs-tokio")]
+async fn read_file_part(path: &Path, offset: u64, size: usize) -> Result<Bytes> {
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    let mut file = tokio::fs::File::open(path).await?;
+    file.seek(std::io::SeekFrom::Start(offset)).await?;
+    let mut buf = Vec::with_capacity(size);
+    file.take(size as u64).read_to_end(&mut buf).await?;
+    Ok(buf.into())
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::error::ValueError;
+use crate::time::UtcTime;
+use crate::utils::base64_encode;
+
+/// Policy of browser-based upload using HTTP POST, see [Minio::presigned_post_policy](crate::Minio::presigned_post_policy).
+///
+/// The conditions which the POST form must match.
+/// ## Example
+/// ```rust
+/// use minio_rsc::client::PostPolicy;
+/// use minio_rsc::time::UtcTime;
+/// let expiration = UtcTime::new(chrono::Utc::now() + chrono::Duration::hours(1));
+/// let policy = PostPolicy::new("bucket", expiration)
+///     .key_starts_with("uploads/")
+///     .content_type_starts_with("image/")
+///     .content_length_range(1, 10 * 1024 * 1024)
+///     .success_action_status(201)
+///     .user_metadata("owner", "user1");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PostPolicy {
+    pub(crate) bucket: String,
+    expiration: UtcTime,
+    /// conditions of form fields, (operator, field, value).
+    conditions: Vec<(&'static str, String, String)>,
+    content_length_range: Option<(u64, u64)>,
+}
+
+impl PostPolicy {
+    /// Create a policy of uploading to `bucket`, which expires at `expiration`.
+    pub fn new<S: Into<String>>(bucket: S, expiration: UtcTime) -> Self {
+        Self {
+            bucket: bucket.into(),
+            expiration,
+            conditions: vec![],
+            content_length_range: None,
+        }
+    }
+
+    /// The object key must be `key`.
+    pub fn key<S: Into<String>>(self, key: S) -> Self {
+        self.condition("eq", "key", key.into())
+    }
+
+    /// The object key must start with `prefix`.
+    pub fn key_starts_with<S: Into<String>>(self, prefix: S) -> Self {
+        self.condition("starts-with", "key", prefix.into())
+    }
+
+    /// The content type must be `content_type`.
+    pub fn content_type<S: Into<String>>(self, content_type: S) -> Self {
+        self.condition("eq", "Content-Type", content_type.into())
+    }
+
+    /// The content type must start with `prefix`.
+    pub fn content_type_starts_with<S: Into<String>>(self, prefix: S) -> Self {
+        self.condition("starts-with", "Content-Type", prefix.into())
+    }
+
+    /// The byte length of the uploaded file must be between `min` and `max`.
+    pub fn content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.content_length_range = Some((min, max));
+        self
+    }
+
+    /// The status code of the response on success, valid values: 200 | 201 | 204.
+    pub fn success_action_status(self, status: u16) -> Self {
+        self.condition("eq", "success_action_status", status.to_string())
+    }
+
+    /// The user metadata `x-amz-meta-<key>` must be `value`.
+    pub fn user_metadata<K: AsRef<str>, V: Into<String>>(self, key: K, value: V) -> Self {
+        let field = format!("x-amz-meta-{}", key.as_ref());
+        self.condition("eq", &field, value.into())
+    }
+
+    /// Set the condition of `field`, replacing the old one.
+    fn condition(mut self, operator: &'static str, field: &str, value: String) -> Self {
+        self.conditions.retain(|(_, f, _)| f != field);
+        self.conditions.push((operator, field.to_string(), value));
+        self
+    }
+
+    /// Returns the base64 encoded policy with the `signed_fields`,
+    /// and the form fields of the conditions.
+    pub(crate) fn encode(
+        &self,
+        signed_fields: &[(&str, &str)],
+    ) -> Result<(String, HashMap<String, String>), ValueError> {
+        if !self.conditions.iter().any(|(_, f, _)| f == "key") {
+            return Err(ValueError::from("key condition must be set"));
+        }
+        let mut form_data = HashMap::new();
+        let mut conditions: Vec<Value> = vec![json!(["eq", "$bucket", self.bucket])];
+        form_data.insert("bucket".to_string(), self.bucket.clone());
+        for (operator, field, value) in &self.conditions {
+            conditions.push(json!([operator, format!("${}", field), value]));
+            form_data.insert(field.clone(), value.clone());
+        }
+        if let Some((min, max)) = self.content_length_range {
+            conditions.push(json!(["content-length-range", min, max]));
+        }
+        for (field, value) in signed_fields {
+            conditions.push(json!(["eq", format!("${}", field), value]));
+        }
+        let policy = json!({
+            "expiration": self.expiration.format_time(),
+            "conditions": conditions,
+        });
+        Ok((base64_encode(policy.to_string()), form_data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use serde_json::{json, Value};
+
+    use super::PostPolicy;
+    use crate::time::UtcTime;
+
+    #[test]
+    fn test_post_policy() {
+        let expiration: UtcTime = chrono::DateTime::parse_from_rfc3339("2030-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc)
+            .into();
+        let policy = PostPolicy::new("bucket", expiration);
+        assert!(policy.encode(&[]).is_err());
+
+        let policy = policy
+            .key("uploads/a.jpg")
+            .key_starts_with("uploads/")
+            .content_type("image/jpeg")
+            .content_length_range(1, 1024)
+            .success_action_status(201)
+            .user_metadata("owner", "user1");
+        let (encoded, form_data) = policy
+            .encode(&[("x-amz-date", "20300101T000000Z")])
+            .unwrap();
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .unwrap();
+        let decoded: Value = serde_json::from_slice(&decoded).unwrap();
+        assert_eq!(decoded["expiration"], "2030-01-01T00:00:00.000Z");
+        assert_eq!(
+            decoded["conditions"],
+            json!([
+                ["eq", "$bucket", "bucket"],
+                ["starts-with", "$key", "uploads/"],
+                ["eq", "$Content-Type", "image/jpeg"],
+                ["eq", "$success_action_status", "201"],
+                ["eq", "$x-amz-meta-owner", "user1"],
+                ["content-length-range", 1, 1024],
+                ["eq", "$x-amz-date", "20300101T000000Z"],
+            ])
+        );
+        assert_eq!(form_data["key"], "uploads/");
+        assert_eq!(form_data["bucket"], "bucket");
+        assert_eq!(form_data["x-amz-meta-owner"], "user1");
+        assert!(!form_data.contains_key("x-amz-date"));
+    }
+}
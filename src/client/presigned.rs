@@ -1,11 +1,12 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use hyper::HeaderMap;
 use hyper::{Method, Uri};
 
-use super::{PresignedArgs, QueryMap};
+use super::{PostPolicy, PresignedArgs, QueryMap};
 use crate::error::{Result, ValueError};
-use crate::signer::presign_v4;
+use crate::signer::{get_credential_string, post_presign_v4, presign_v4_with_headers};
 use crate::time::UtcTime;
 use crate::utils::urlencode_binary;
 use crate::Minio;
@@ -22,6 +23,7 @@ impl Minio {
     /// - request_date: Optional request_date argument to specify a different request date. Default is current date.
     /// - version_id: Version ID of the object.
     /// - extra_query_params: Extra query parameters for advanced usage.
+    /// - signed_headers: Headers bound into the signature.
     async fn _get_presigned_url<T1: Into<String>, T2: Into<String>>(
         &self,
         method: Method,
@@ -32,6 +34,7 @@ impl Minio {
         request_date: Option<UtcTime>,
         version_id: Option<String>,
         extra_query_params: Option<QueryMap>,
+        signed_headers: HeaderMap,
     ) -> Result<String> {
         if expires < 1 || expires > 604800 {
             return Err(ValueError::from("expires must be between 1 second to 7 days").into());
@@ -42,23 +45,24 @@ impl Minio {
             query.insert("versionId".to_string(), id);
         }
         let credentials = self.fetch_credentials().await;
-        if let Some(token) = credentials.session_token() {
-            query.insert("X-Amz-Security-Token".to_string(), token.to_string());
-        }
         if let Some(headers) = response_headers {
             for (name, value) in &headers {
                 query.insert(name.to_string(), urlencode_binary(value.as_bytes(), false));
             }
         }
-        let uri = self._build_uri(Some(bucket_name.into()), Some(object_name.into()));
+        let bucket_name: String = bucket_name.into();
+        let region = self._get_region(&bucket_name).await?;
+        let uri = self._build_uri(Some(bucket_name), Some(object_name.into()));
         let uri = uri + "?" + &query.to_query_string();
         let uri = Uri::from_str(&uri).map_err(|e| ValueError::new(e.to_string()))?;
-        let r = presign_v4(
+        let r = presign_v4_with_headers(
             &method,
             &uri,
-            self.region(),
+            &signed_headers,
+            &region,
             credentials.access_key(),
             credentials.secret_key(),
+            credentials.session_token().map(|x| x.as_str()),
             &date,
             expires,
         );
@@ -82,17 +86,7 @@ impl Minio {
     /// # }
     /// ```
     pub async fn presigned_get_object(&self, args: PresignedArgs) -> Result<String> {
-        self._get_presigned_url(
-            Method::GET,
-            args.bucket_name,
-            args.object_name,
-            args.expires,
-            args.headers,
-            args.request_date,
-            args.version_id,
-            Some(args.querys),
-        )
-        .await
+        self.presigned_url(Method::GET, args).await
     }
 
     /// Get presigned URL of an object to upload data with expiry time.
@@ -112,8 +106,45 @@ impl Minio {
     /// # }
     /// ```
     pub async fn presigned_put_object(&self, args: PresignedArgs) -> Result<String> {
+        self.presigned_url(Method::PUT, args).await
+    }
+
+    /// Get presigned URL of an object for any HTTP method with expiry time,
+    /// like `HEAD`, `DELETE` or `PUT` of a multipart upload part.
+    ///
+    /// The headers set by [PresignedArgs::signed_header] are signed,
+    /// and the client must send them with the same values.
+    /// ## Example
+    /// ``` rust
+    /// # use minio_rsc::Minio;
+    /// # use minio_rsc::client::PresignedArgs;
+    /// use hyper::Method;
+    /// # async fn example(minio: Minio){
+    /// let head_url: String = minio
+    ///     .presigned_url(Method::HEAD, PresignedArgs::new("bucket", "file.txt"))
+    ///     .await
+    ///     .unwrap();
+    /// let delete_url: String = minio
+    ///     .presigned_url(Method::DELETE, PresignedArgs::new("bucket", "file.txt").expires(600))
+    ///     .await
+    ///     .unwrap();
+    /// // upload the part 1 of a multipart upload, with the content type bound.
+    /// let upload_part_url: String = minio
+    ///     .presigned_url(
+    ///         Method::PUT,
+    ///         PresignedArgs::new("bucket", "file.txt")
+    ///             .query("partNumber", "1")
+    ///             .query("uploadId", "upload_id")
+    ///             .signed_header("content-type", "text/plain")
+    ///             .unwrap(),
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn presigned_url(&self, method: Method, args: PresignedArgs) -> Result<String> {
         self._get_presigned_url(
-            Method::PUT,
+            method,
             args.bucket_name,
             args.object_name,
             args.expires,
@@ -121,7 +152,54 @@ impl Minio {
             args.request_date,
             args.version_id,
             Some(args.querys),
+            args.signed_headers,
         )
         .await
     }
+
+    /// Get the form fields of browser-based upload using HTTP POST, signed with the [PostPolicy].
+    ///
+    /// The returned fields include `policy`, `x-amz-signature`, `x-amz-credential`,
+    /// `x-amz-date`, `x-amz-algorithm` and `x-amz-security-token` of temporary credentials,
+    /// as well as the fields of the `eq` and `starts-with` conditions.
+    /// Post them with the `file` field to the bucket URL.
+    /// ## Example
+    /// ``` rust
+    /// # use minio_rsc::Minio;
+    /// use minio_rsc::client::PostPolicy;
+    /// use minio_rsc::time::UtcTime;
+    /// # async fn example(minio: Minio){
+    /// let expiration = UtcTime::new(chrono::Utc::now() + chrono::Duration::hours(1));
+    /// let policy = PostPolicy::new("bucket", expiration)
+    ///     .key_starts_with("uploads/")
+    ///     .content_length_range(1, 10 * 1024 * 1024);
+    /// let form_data = minio.presigned_post_policy(policy).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn presigned_post_policy(
+        &self,
+        policy: PostPolicy,
+    ) -> Result<HashMap<String, String>> {
+        let date = UtcTime::now();
+        let credentials = self.fetch_credentials().await;
+        let region = self._get_region(&policy.bucket).await?;
+        let credential = get_credential_string(credentials.access_key(), &date, &region);
+        let amz_date = date.aws_format_time();
+        let mut signed_fields = vec![
+            ("x-amz-algorithm", "AWS4-HMAC-SHA256"),
+            ("x-amz-credential", credential.as_str()),
+            ("x-amz-date", amz_date.as_str()),
+        ];
+        if let Some(token) = credentials.session_token() {
+            signed_fields.push(("x-amz-security-token", token.as_str()));
+        }
+        let (encoded, mut form_data) = policy.encode(&signed_fields)?;
+        let signature = post_presign_v4(&encoded, credentials.secret_key(), &date, &region);
+        for (field, value) in signed_fields {
+            form_data.insert(field.to_string(), value.to_string());
+        }
+        form_data.insert("policy".to_string(), encoded);
+        form_data.insert("x-amz-signature".to_string(), signature);
+        Ok(form_data)
+    }
 }
@@ -1,4 +1,14 @@
 use std::collections::HashMap;
+use std::pin::Pin;
+
+use bytes::Bytes;
+use futures::{Stream, StreamExt, TryStreamExt};
+use hyper::{header, HeaderMap};
+use reqwest::Response;
+
+use crate::datatype::{CommonPrefix, DeleteError, DeleteMarker, DeletedObject};
+use crate::datatype::{Object, ObjectVersion};
+use crate::error::Result;
 
 #[derive(Debug, Clone)]
 pub struct ObjectStat {
@@ -9,6 +19,8 @@ pub struct ObjectStat {
     pub(crate) content_type: String,
     pub(crate) version_id: String,
     pub(crate) size: usize,
+    pub(crate) storage_class: Option<String>,
+    pub(crate) server_side_encryption: Option<String>,
     pub(crate) metadata: HashMap<String, String>,
 }
 
@@ -41,7 +53,280 @@ impl ObjectStat {
         self.size
     }
 
+    /// The storage class of the object, [None] for `STANDARD`.
+    pub fn storage_class(&self) -> Option<&str> {
+        self.storage_class.as_deref()
+    }
+
+    /// The server-side encryption algorithm, `AES256` or `aws:kms`.
+    pub fn server_side_encryption(&self) -> Option<&str> {
+        self.server_side_encryption.as_deref()
+    }
+
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+}
+
+/// Get the value of header `name` as string.
+fn header_str(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(ToString::to_string)
+}
+
+/// Get the user-defined metadata from `x-amz-meta-*` headers.
+pub(crate) fn parse_user_metadata(headers: &HeaderMap) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    for (k, v) in headers {
+        if let Some(key) = k.as_str().strip_prefix("x-amz-meta-") {
+            if let Ok(value) = String::from_utf8(v.as_bytes().to_vec()) {
+                metadata.insert(key.to_string(), value);
+            }
+        }
+    }
+    metadata
+}
+
+/// Response of `get_object`, with the object metadata parsed from headers and the body.
+///
+/// The body can be consumed by [bytes](Self::bytes), [text](Self::text),
+/// [into_stream](Self::into_stream), or [into_async_read](Self::into_async_read)
+/// with feature `io-tokio`.
+pub struct GetObjectOutput {
+    bucket_name: String,
+    object_name: String,
+    content_length: usize,
+    content_range: Option<String>,
+    content_type: String,
+    last_modified: String,
+    etag: String,
+    version_id: Option<String>,
+    server_side_encryption: Option<String>,
+    ssekms_key_id: Option<String>,
+    sse_customer_algorithm: Option<String>,
+    checksum_crc32: Option<String>,
+    checksum_crc32c: Option<String>,
+    checksum_sha1: Option<String>,
+    checksum_sha256: Option<String>,
+    metadata: HashMap<String, String>,
+    body: Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>,
+}
+
+impl GetObjectOutput {
+    pub(crate) fn new(bucket_name: String, object_name: String, res: Response) -> Self {
+        let headers = res.headers();
+        Self {
+            bucket_name,
+            object_name,
+            content_length: header_str(headers, header::CONTENT_LENGTH.as_str())
+                .and_then(|x| x.parse().ok())
+                .unwrap_or(0),
+            content_range: header_str(headers, header::CONTENT_RANGE.as_str()),
+            content_type: header_str(headers, header::CONTENT_TYPE.as_str()).unwrap_or_default(),
+            last_modified: header_str(headers, header::LAST_MODIFIED.as_str()).unwrap_or_default(),
+            etag: header_str(headers, header::ETAG.as_str())
+                .unwrap_or_default()
+                .replace('"', ""),
+            version_id: header_str(headers, "x-amz-version-id"),
+            server_side_encryption: header_str(headers, "x-amz-server-side-encryption"),
+            ssekms_key_id: header_str(headers, "x-amz-server-side-encryption-aws-kms-key-id"),
+            sse_customer_algorithm: header_str(
+                headers,
+                "x-amz-server-side-encryption-customer-algorithm",
+            ),
+            checksum_crc32: header_str(headers, "x-amz-checksum-crc32"),
+            checksum_crc32c: header_str(headers, "x-amz-checksum-crc32c"),
+            checksum_sha1: header_str(headers, "x-amz-checksum-sha1"),
+            checksum_sha256: header_str(headers, "x-amz-checksum-sha256"),
+            metadata: parse_user_metadata(headers),
+            body: Box::pin(res.bytes_stream().map_err(Into::into)),
+        }
+    }
+
+    pub fn bucket_name(&self) -> &str {
+        self.bucket_name.as_ref()
+    }
+
+    pub fn object_name(&self) -> &str {
+        self.object_name.as_ref()
+    }
+
+    /// Byte length of the body, which is the length of the range if requested.
+    pub fn content_length(&self) -> usize {
+        self.content_length
+    }
+
+    /// The `Content-Range` header, like `bytes 0-9/443`, only returned for ranged requests.
+    pub fn content_range(&self) -> Option<&str> {
+        self.content_range.as_deref()
+    }
+
+    pub fn content_type(&self) -> &str {
+        self.content_type.as_ref()
+    }
+
+    pub fn last_modified(&self) -> &str {
+        self.last_modified.as_ref()
+    }
+
+    pub fn etag(&self) -> &str {
+        self.etag.as_ref()
+    }
+
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+
+    /// The server-side encryption algorithm, `AES256` or `aws:kms`.
+    pub fn server_side_encryption(&self) -> Option<&str> {
+        self.server_side_encryption.as_deref()
+    }
+
+    /// The ID of the KMS key, if the object is encrypted with SSE-KMS.
+    pub fn ssekms_key_id(&self) -> Option<&str> {
+        self.ssekms_key_id.as_deref()
+    }
+
+    /// The encryption algorithm, if the object is encrypted with SSE-C.
+    pub fn sse_customer_algorithm(&self) -> Option<&str> {
+        self.sse_customer_algorithm.as_deref()
+    }
+
+    pub fn checksum_crc32(&self) -> Option<&str> {
+        self.checksum_crc32.as_deref()
+    }
+
+    pub fn checksum_crc32c(&self) -> Option<&str> {
+        self.checksum_crc32c.as_deref()
+    }
+
+    pub fn checksum_sha1(&self) -> Option<&str> {
+        self.checksum_sha1.as_deref()
+    }
+
+    pub fn checksum_sha256(&self) -> Option<&str> {
+        self.checksum_sha256.as_deref()
+    }
+
+    /// User-defined metadata, without the `x-amz-meta-` prefix.
     pub fn metadata(&self) -> &HashMap<String, String> {
         &self.metadata
     }
+
+    /// Get the full body as [Bytes].
+    pub async fn bytes(self) -> Result<Bytes> {
+        let mut data = Vec::with_capacity(self.content_length);
+        let mut body = self.body;
+        while let Some(chunk) = body.next().await {
+            data.extend_from_slice(&chunk?);
+        }
+        Ok(data.into())
+    }
+
+    /// Get the full body as text, decoded lossily as UTF-8.
+    pub async fn text(self) -> Result<String> {
+        let data = self.bytes().await?;
+        Ok(String::from_utf8_lossy(&data).into_owned())
+    }
+
+    /// Get the body as a stream of [Bytes].
+    pub fn into_stream(self) -> Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>> {
+        self.body
+    }
+
+    /// Get the body as [tokio::io::AsyncRead].
+    /// ## Example
+    /// ```rust
+    /// use tokio::io::AsyncReadExt;
+    /// # use minio_rsc::Minio;
+    /// # use minio_rsc::error::Result;
+    /// # async fn example(minio: Minio)->Result<()>{
+    /// let mut reader = minio.get_object("bucket", "file.txt").await?.into_async_read();
+    /// let mut text = String::new();
+    /// reader.read_to_string(&mut text).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "io-tokio")]
+    pub fn into_async_read(self) -> GetObjectReader {
+        GetObjectReader {
+            body: self.body,
+            chunk: Bytes::new(),
+        }
+    }
+}
+
+impl std::fmt::Debug for GetObjectOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GetObjectOutput")
+            .field("bucket_name", &self.bucket_name)
+            .field("object_name", &self.object_name)
+            .field("content_length", &self.content_length)
+            .field("content_range", &self.content_range)
+            .field("etag", &self.etag)
+            .field("version_id", &self.version_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// [tokio::io::AsyncRead] over the body of [GetObjectOutput].
+#[cfg(feature = "io-tokio")]
+pub struct GetObjectReader {
+    body: Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>,
+    chunk: Bytes,
+}
+
+#[cfg(feature = "io-tokio")]
+impl tokio::io::AsyncRead for GetObjectReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        use std::task::Poll;
+
+        while self.chunk.is_empty() {
+            match self.body.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => self.chunk = chunk,
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Err(std::io::Error::other(e))),
+                Poll::Ready(None) => return Poll::Ready(Ok(())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        let n = buf.remaining().min(self.chunk.len());
+        let data = self.chunk.split_to(n);
+        buf.put_slice(&data);
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Item yielded by `list_objects_stream`.
+#[derive(Debug, Clone)]
+pub enum ListObjectsItem {
+    /// An object under the listed prefix.
+    Object(Object),
+    /// A common prefix grouped by the delimiter, only returned in non-recursive listing.
+    CommonPrefix(CommonPrefix),
+}
+
+/// Item yielded by `list_object_versions_stream`.
+#[derive(Debug, Clone)]
+pub enum ListObjectVersionsItem {
+    /// A version of an object.
+    Version(ObjectVersion),
+    /// A delete marker of an object.
+    DeleteMarker(DeleteMarker),
+    /// A common prefix grouped by the delimiter.
+    CommonPrefix(CommonPrefix),
+}
+
+/// Item yielded by `remove_objects`, the result of deleting a single key.
+#[derive(Debug, Clone)]
+pub enum RemoveObjectsItem {
+    /// The key was deleted successfully, only returned in non-quiet mode.
+    Deleted(DeletedObject),
+    /// The key could not be deleted.
+    Error(DeleteError),
 }
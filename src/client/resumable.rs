@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use super::MultipartUploadTask;
+use crate::datatype::Part;
+
+pub type CheckpointFuture<'a, T> = Pin<Box<dyn Future<Output = std::io::Result<T>> + Send + 'a>>;
+
+/// Progress of a resumable multipart upload, persisted by a [CheckpointStore].
+///
+/// Records the multipart upload and the parts completed so far,
+/// with the size and modification time of the source file to detect changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadCheckpoint {
+    pub bucket: String,
+    pub key: String,
+    pub upload_id: String,
+    pub bucket_owner: Option<String>,
+    /// total byte length of the source.
+    pub size: u64,
+    /// modification time of the source, in milliseconds since the unix epoch.
+    pub modified: Option<i64>,
+    pub part_size: usize,
+    pub parts: Vec<Part>,
+}
+
+impl UploadCheckpoint {
+    /// Create a checkpoint of `task` without completed parts.
+    pub fn new(
+        task: &MultipartUploadTask,
+        size: u64,
+        modified: Option<i64>,
+        part_size: usize,
+    ) -> Self {
+        Self {
+            bucket: task.bucket().to_string(),
+            key: task.key().to_string(),
+            upload_id: task.upload_id().to_string(),
+            bucket_owner: task.bucket_owner().cloned(),
+            size,
+            modified,
+            part_size,
+            parts: vec![],
+        }
+    }
+
+    /// Get the [MultipartUploadTask] of the checkpoint.
+    pub fn task(&self) -> MultipartUploadTask {
+        MultipartUploadTask::new(
+            self.bucket.clone(),
+            self.key.clone(),
+            self.upload_id.clone(),
+            self.bucket_owner.clone(),
+            None,
+            None,
+        )
+    }
+
+    /// Get the id of the checkpoint uploading `path` to `bucket/key`.
+    pub fn id(bucket: &str, key: &str, path: &str) -> String {
+        format!(
+            "{:x}",
+            md5::compute(format!("{}\n{}\n{}", bucket, key, path))
+        )
+    }
+}
+
+/// Storage of [UploadCheckpoint], used by `fput_object_resumable`.
+///
+/// Implement it to save the checkpoints in a database or other places.
+pub trait CheckpointStore: Send + Sync {
+    /// Load the checkpoint with `id`, `None` if not exists.
+    fn load<'a>(&'a self, id: &'a str) -> CheckpointFuture<'a, Option<UploadCheckpoint>>;
+
+    /// Save the checkpoint with `id`, replacing the old one.
+    fn save<'a>(
+        &'a self,
+        id: &'a str,
+        checkpoint: &'a UploadCheckpoint,
+    ) -> CheckpointFuture<'a, ()>;
+
+    /// Remove the checkpoint with `id`.
+    fn remove<'a>(&'a self, id: &'a str) -> CheckpointFuture<'a, ()>;
+}
+
+/// [CheckpointStore] keeping the checkpoints in memory,
+/// which only allows resuming within the same process.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCheckpointStore(Arc<Mutex<HashMap<String, UploadCheckpoint>>>);
+
+impl MemoryCheckpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of checkpoints.
+    pub fn len(&self) -> usize {
+        self.0.lock().map_or(0, |m| m.len())
+    }
+
+    /// Returns `true` if there is no checkpoint.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    fn load<'a>(&'a self, id: &'a str) -> CheckpointFuture<'a, Option<UploadCheckpoint>> {
+        let checkpoint = self.0.lock().ok().and_then(|m| m.get(id).cloned());
+        Box::pin(async move { Ok(checkpoint) })
+    }
+
+    fn save<'a>(
+        &'a self,
+        id: &'a str,
+        checkpoint: &'a UploadCheckpoint,
+    ) -> CheckpointFuture<'a, ()> {
+        if let Ok(mut m) = self.0.lock() {
+            m.insert(id.to_string(), checkpoint.clone());
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn remove<'a>(&'a self, id: &'a str) -> CheckpointFuture<'a, ()> {
+        if let Ok(mut m) = self.0.lock() {
+            m.remove(id);
+        }
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// [CheckpointStore] saving each checkpoint as a json file in a directory.
+/// ## Example
+/// ```rust
+/// use minio_rsc::client::FileCheckpointStore;
+/// let store = FileCheckpointStore::new("/tmp/minio-checkpoints");
+/// ```
+#[cfg(feature = "fs-tokio")]
+#[derive(Debug, Clone)]
+pub struct FileCheckpointStore {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(feature = "fs-tokio")]
+impl FileCheckpointStore {
+    /// The directory is created when the first checkpoint is saved.
+    pub fn new<P: Into<std::path::PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, id: &str) -> std::path::PathBuf {
+        self.dir.join(format!("{}.json", id))
+    }
+}
+
+#[cfg(feature = "fs-tokio")]
+impl CheckpointStore for FileCheckpointStore {
+    fn load<'a>(&'a self, id: &'a str) -> CheckpointFuture<'a, Option<UploadCheckpoint>> {
+        Box::pin(async move {
+            match tokio::fs::read(self.path(id)).await {
+                // a corrupted checkpoint is ignored, the upload starts over.
+                Ok(data) => Ok(serde_json::from_slice(&data).ok()),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e),
+            }
+        })
+    }
+
+    fn save<'a>(
+        &'a self,
+        id: &'a str,
+        checkpoint: &'a UploadCheckpoint,
+    ) -> CheckpointFuture<'a, ()> {
+        Box::pin(async move {
+            let data = serde_json::to_vec(checkpoint)?;
+            tokio::fs::create_dir_all(&self.dir).await?;
+            // write to a temporary file first, so a crash never leaves a truncated checkpoint.
+            let tmp = self.dir.join(format!("{}.json.tmp", id));
+            tokio::fs::write(&tmp, data).await?;
+            tokio::fs::rename(tmp, self.path(id)).await
+        })
+    }
+
+    fn remove<'a>(&'a self, id: &'a str) -> CheckpointFuture<'a, ()> {
+        Box::pin(async move {
+            match tokio::fs::remove_file(self.path(id)).await {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            }
+        })
+    }
+}
+
+#[cfg(all(test, feature = "fs-tokio"))]
+mod tests {
+    use super::{CheckpointStore, FileCheckpointStore, UploadCheckpoint};
+    use crate::client::MultipartUploadTask;
+    use crate::datatype::Part;
+
+    #[tokio::test]
+    async fn test_file_checkpoint_store() {
+        let dir = std::env::temp_dir().join("minio-rsc-test-checkpoint");
+        let store = FileCheckpointStore::new(&dir);
+        let task = MultipartUploadTask::new(
+            "bucket".to_string(),
+            "key".to_string(),
+            "upload-id".to_string(),
+            None,
+            None,
+            None,
+        );
+        let mut checkpoint = UploadCheckpoint::new(&task, 1024, Some(1), 512);
+        checkpoint.parts.push(Part {
+            e_tag: "\"etag\"".to_string(),
+            part_number: 1,
+        });
+        let id = UploadCheckpoint::id("bucket", "key", "file");
+        store.save(&id, &checkpoint).await.unwrap();
+        let loaded = store.load(&id).await.unwrap().unwrap();
+        assert_eq!(loaded.upload_id, "upload-id");
+        assert_eq!(loaded.parts.len(), 1);
+        assert_eq!(loaded.parts[0].e_tag, "\"etag\"");
+        store.remove(&id).await.unwrap();
+        assert!(store.load(&id).await.unwrap().is_none());
+        store.remove(&id).await.unwrap();
+        let _ = std::fs::remove_dir(dir);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// S3 error codes which are retried by default.
+const RETRYABLE_S3_CODES: [&str; 10] = [
+    "RequestError",
+    "RequestTimeout",
+    "Throttling",
+    "ThrottlingException",
+    "RequestLimitExceeded",
+    "RequestThrottled",
+    "InternalError",
+    "ExpiredToken",
+    "ExpiredTokenException",
+    "SlowDown",
+];
+
+/// HTTP status codes which are retried by default.
+const RETRYABLE_STATUS_CODES: [u16; 8] = [408, 429, 499, 500, 502, 503, 504, 520];
+
+/// Retry policy of the requests sent to S3 service.
+///
+/// A request is retried when the connection fails,
+/// or S3 service responds a retryable HTTP status code or S3 error code.
+///
+/// The delay before the `n`th retry is `min(max_delay, base_delay * 2^(n-1))`,
+/// randomly reduced by up to half as jitter.
+///
+/// **Note**: Requests with a stream body are not replayable and are never retried,
+/// unless `multi_chunked_encoding` is `false`.
+/// ## Example
+/// ```rust
+/// use std::time::Duration;
+/// use minio_rsc::client::RetryPolicy;
+/// let policy = RetryPolicy::default()
+///     .max_attempts(5)
+///     .base_delay(Duration::from_millis(100))
+///     .max_delay(Duration::from_secs(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: usize,
+    base_delay: Duration,
+    max_delay: Duration,
+    retryable_codes: Vec<String>,
+    retryable_status_codes: Vec<u16>,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// Set the maximum number of attempts, including the first one.
+    ///
+    /// Default: `10`
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry.
+    ///
+    /// Default: `200ms`
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the maximum delay between two attempts.
+    ///
+    /// Default: `1s`
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set the S3 error codes to retry, like `SlowDown`, `RequestTimeout`.
+    pub fn retryable_codes<I, T>(mut self, codes: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.retryable_codes = codes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the HTTP status codes to retry, like `500`, `503`.
+    pub fn retryable_status_codes<I: IntoIterator<Item = u16>>(mut self, codes: I) -> Self {
+        self.retryable_status_codes = codes.into_iter().collect();
+        self
+    }
+
+    #[inline]
+    pub(crate) fn attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    #[inline]
+    pub(crate) fn is_retryable_code(&self, code: &str) -> bool {
+        self.retryable_codes.iter().any(|c| c == code)
+    }
+
+    #[inline]
+    pub(crate) fn is_retryable_status(&self, status: u16) -> bool {
+        self.retryable_status_codes.contains(&status)
+    }
+
+    /// Get the delay before the retry after `attempt` failed attempts.
+    pub(crate) fn delay(&self, attempt: usize) -> Duration {
+        let exp = attempt.saturating_sub(1).min(31) as u32;
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(exp))
+            .min(self.max_delay);
+        let half = delay / 2;
+        let random = RandomState::new().build_hasher().finish();
+        let jitter = half.mul_f64((random % 1000) as f64 / 1000.0);
+        half + jitter
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(1),
+            retryable_codes: RETRYABLE_S3_CODES.iter().map(|c| c.to_string()).collect(),
+            retryable_status_codes: RETRYABLE_STATUS_CODES.to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::RetryPolicy;
+
+    #[test]
+    fn test_retry_delay() {
+        let policy = RetryPolicy::default()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(1000));
+        for (attempt, max) in [
+            (1, 100),
+            (2, 200),
+            (3, 400),
+            (4, 800),
+            (5, 1000),
+            (60, 1000),
+        ] {
+            let delay = policy.delay(attempt);
+            assert!(delay >= Duration::from_millis(max / 2));
+            assert!(delay <= Duration::from_millis(max));
+        }
+        assert!(policy.is_retryable_code("SlowDown"));
+        assert!(policy.is_retryable_status(503));
+        assert!(!policy.is_retryable_status(404));
+        assert_eq!(RetryPolicy::none().attempts(), 1);
+    }
+}
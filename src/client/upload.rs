@@ -0,0 +1,95 @@
+use crate::signer::{MAX_MULTIPART_COUNT, MAX_PART_SIZE, MIN_PART_SIZE};
+
+/// Multipart upload configuration of `put_object_stream` and `fput_object`.
+///
+/// Streams of unknown length, or of at least 5MiB when `multi_chunked_encoding` is disabled,
+/// are uploaded by multipart upload in parts of `part_size`,
+/// keeping up to `max_concurrency` parts uploading at the same time.
+/// The number of concurrent parts is also limited by `memory_budget`,
+/// the maximum bytes of part buffers held in memory, including the part being read.
+/// ## Example
+/// ```rust
+/// use minio_rsc::client::UploadConfig;
+/// let config = UploadConfig::default()
+///     .part_size(32 * 1024 * 1024)
+///     .max_concurrency(8)
+///     .memory_budget(512 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UploadConfig {
+    part_size: usize,
+    max_concurrency: usize,
+    memory_budget: usize,
+}
+
+impl UploadConfig {
+    /// Set the size of each part, between 5MiB and 5GiB.
+    ///
+    /// The part size is increased if the object would exceed 10000 parts.
+    ///
+    /// Default: `16MiB`
+    pub fn part_size(mut self, part_size: usize) -> Self {
+        self.part_size = part_size.clamp(MIN_PART_SIZE, MAX_PART_SIZE);
+        self
+    }
+
+    /// Set the maximum number of parts uploaded at the same time.
+    ///
+    /// Default: `4`
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Set the maximum bytes of part buffers held in memory.
+    ///
+    /// Default: `128MiB`
+    pub fn memory_budget(mut self, memory_budget: usize) -> Self {
+        self.memory_budget = memory_budget;
+        self
+    }
+
+    /// Get the part size for an object of `len` bytes.
+    pub(crate) fn part_size_for(&self, len: Option<usize>) -> usize {
+        let min_size = len.map_or(0, |len| len.div_ceil(MAX_MULTIPART_COUNT));
+        self.part_size.max(min_size).min(MAX_PART_SIZE)
+    }
+
+    /// Get the number of parts uploaded at the same time with `part_size`.
+    pub(crate) fn concurrency(&self, part_size: usize) -> usize {
+        let budget = (self.memory_budget / part_size.max(1)).saturating_sub(1);
+        budget.clamp(1, self.max_concurrency)
+    }
+}
+
+impl Default for UploadConfig {
+    fn default() -> Self {
+        Self {
+            part_size: 16 * 1024 * 1024,
+            max_concurrency: 4,
+            memory_budget: 128 * 1024 * 1024,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UploadConfig;
+    use crate::signer::{MAX_MULTIPART_COUNT, MIN_PART_SIZE};
+
+    #[test]
+    fn test_upload_config() {
+        let config = UploadConfig::default().part_size(MIN_PART_SIZE);
+        assert_eq!(config.part_size_for(None), MIN_PART_SIZE);
+        assert_eq!(config.part_size_for(Some(1024)), MIN_PART_SIZE);
+        let len = MIN_PART_SIZE * MAX_MULTIPART_COUNT + 1;
+        assert_eq!(config.part_size_for(Some(len)), MIN_PART_SIZE + 1);
+
+        let config = UploadConfig::default()
+            .max_concurrency(8)
+            .memory_budget(5 * MIN_PART_SIZE);
+        assert_eq!(config.concurrency(MIN_PART_SIZE), 4);
+        assert_eq!(config.concurrency(10 * MIN_PART_SIZE), 1);
+        assert_eq!(config.max_concurrency(2).concurrency(MIN_PART_SIZE), 2);
+    }
+}
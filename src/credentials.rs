@@ -35,7 +35,7 @@ impl Credentials {
     }
 
     /// Check whether this credentials expired or not.
-    pub fn is_expired(self) -> bool {
+    pub fn is_expired(&self) -> bool {
         if let Some(exp) = self.expiration {
             let now = UtcTime::now();
             now.before(exp - 10)
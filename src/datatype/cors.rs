@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+/// Cross-origin resource sharing (CORS) configuration of a bucket,
+/// a set of up to 100 rules.
+///
+/// See `get_bucket_cors` and `set_bucket_cors` API.
+/// ## Example
+/// ```rust
+/// use minio_rsc::datatype::{CorsConfiguration, CorsRule};
+/// // allow browsers of `https://example.com` to upload by presigned url.
+/// let config = CorsConfiguration::new(vec![CorsRule::new(
+///     vec!["https://example.com".to_string()],
+///     vec!["PUT".to_string(), "GET".to_string()],
+/// )
+/// .allowed_headers(vec!["*".to_string()])
+/// .expose_headers(vec!["ETag".to_string()])
+/// .max_age_seconds(3000)]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename = "CORSConfiguration", rename_all = "PascalCase")]
+pub struct CorsConfiguration {
+    #[serde(rename = "CORSRule", default)]
+    pub rules: Vec<CorsRule>,
+}
+
+impl CorsConfiguration {
+    pub fn new(rules: Vec<CorsRule>) -> Self {
+        Self { rules }
+    }
+}
+
+/// A CORS rule of [CorsConfiguration].
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CorsRule {
+    /// Unique identifier of the rule, up to 255 characters.
+    #[serde(rename = "ID")]
+    pub id: Option<String>,
+    /// Origins allowed to access the bucket, like `https://example.com` or `*`.
+    #[serde(rename = "AllowedOrigin", default)]
+    pub allowed_origins: Vec<String>,
+    /// Valid Values: GET | PUT | HEAD | POST | DELETE
+    #[serde(rename = "AllowedMethod", default)]
+    pub allowed_methods: Vec<String>,
+    /// Headers allowed in the `Access-Control-Request-Headers` of preflight requests.
+    #[serde(rename = "AllowedHeader", default)]
+    pub allowed_headers: Vec<String>,
+    /// Response headers which browsers are allowed to access.
+    #[serde(rename = "ExposeHeader", default)]
+    pub expose_headers: Vec<String>,
+    /// Seconds browsers can cache the response of preflight requests.
+    pub max_age_seconds: Option<u32>,
+}
+
+impl CorsRule {
+    /// Allow `methods` from `origins`.
+    pub fn new(origins: Vec<String>, methods: Vec<String>) -> Self {
+        Self {
+            allowed_origins: origins,
+            allowed_methods: methods,
+            ..Default::default()
+        }
+    }
+
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn allowed_headers(mut self, headers: Vec<String>) -> Self {
+        self.allowed_headers = headers;
+        self
+    }
+
+    pub fn expose_headers(mut self, headers: Vec<String>) -> Self {
+        self.expose_headers = headers;
+        self
+    }
+
+    pub fn max_age_seconds(mut self, seconds: u32) -> Self {
+        self.max_age_seconds = Some(seconds);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CorsConfiguration, CorsRule};
+    use crate::datatype::{FromXml, ToXml};
+
+    #[test]
+    fn test_cors_configuration() {
+        let config = CorsConfiguration::new(vec![
+            CorsRule::new(
+                vec!["https://example.com".to_string()],
+                vec!["PUT".to_string(), "GET".to_string()],
+            )
+            .id("upload")
+            .allowed_headers(vec!["*".to_string()])
+            .expose_headers(vec!["ETag".to_string()])
+            .max_age_seconds(3000),
+            CorsRule::new(vec!["*".to_string()], vec!["GET".to_string()]),
+        ]);
+        let xml = config.to_xml().unwrap();
+        assert_eq!(
+            xml,
+            "<CORSConfiguration><CORSRule><ID>upload</ID>\
+            <AllowedOrigin>https://example.com</AllowedOrigin>\
+            <AllowedMethod>PUT</AllowedMethod><AllowedMethod>GET</AllowedMethod>\
+            <AllowedHeader>*</AllowedHeader><ExposeHeader>ETag</ExposeHeader>\
+            <MaxAgeSeconds>3000</MaxAgeSeconds></CORSRule>\
+            <CORSRule><AllowedOrigin>*</AllowedOrigin><AllowedMethod>GET</AllowedMethod></CORSRule>\
+            </CORSConfiguration>"
+        );
+        assert_eq!(CorsConfiguration::from_xml(xml).unwrap(), config);
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use crate::sse::{Sse, SseKMS, SseS3};
+
+/// Default encryption configuration of a bucket,
+/// applied to the objects uploaded without encryption headers.
+///
+/// See `get_bucket_encryption` and `set_bucket_encryption` API.
+/// ## Example
+/// ```rust
+/// use minio_rsc::datatype::ServerSideEncryptionConfiguration;
+/// use minio_rsc::sse::{SseKMS, SseS3};
+/// let config = ServerSideEncryptionConfiguration::from(&SseS3::new());
+/// let config = ServerSideEncryptionConfiguration::from(&SseKMS::new("my-key", None));
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServerSideEncryptionConfiguration {
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<ServerSideEncryptionRule>,
+}
+
+impl ServerSideEncryptionConfiguration {
+    /// Encrypt with SSE-S3.
+    pub fn sse_s3() -> Self {
+        Self::new(SseAlgorithm::AES256, None)
+    }
+
+    /// Encrypt with SSE-KMS, using the default key of the server if `key_id` is None.
+    pub fn sse_kms(key_id: Option<String>) -> Self {
+        Self::new(SseAlgorithm::KMS, key_id)
+    }
+
+    fn new(sse_algorithm: SseAlgorithm, kms_master_key_id: Option<String>) -> Self {
+        Self {
+            rules: vec![ServerSideEncryptionRule {
+                apply_server_side_encryption_by_default: ApplyServerSideEncryptionByDefault {
+                    sse_algorithm,
+                    kms_master_key_id,
+                },
+            }],
+        }
+    }
+}
+
+impl From<&SseS3> for ServerSideEncryptionConfiguration {
+    fn from(_: &SseS3) -> Self {
+        Self::sse_s3()
+    }
+}
+
+impl From<&SseKMS> for ServerSideEncryptionConfiguration {
+    fn from(sse: &SseKMS) -> Self {
+        let key_id = sse
+            .headers()
+            .get("X-Amz-Server-Side-Encryption-Aws-Kms-Key-Id")
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+        Self::sse_kms(key_id)
+    }
+}
+
+/// A default encryption rule of [ServerSideEncryptionConfiguration].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServerSideEncryptionRule {
+    pub apply_server_side_encryption_by_default: ApplyServerSideEncryptionByDefault,
+}
+
+/// The encryption applied by default.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ApplyServerSideEncryptionByDefault {
+    #[serde(rename = "SSEAlgorithm")]
+    pub sse_algorithm: SseAlgorithm,
+    /// The KMS key id, only with [SseAlgorithm::KMS].
+    #[serde(rename = "KMSMasterKeyID")]
+    pub kms_master_key_id: Option<String>,
+}
+
+/// Server-side encryption algorithm, Valid Values: `AES256 | aws:kms`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SseAlgorithm {
+    AES256,
+    #[serde(rename = "aws:kms")]
+    KMS,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatype::{FromXml, ToXml};
+
+    #[test]
+    fn test_server_side_encryption_configuration() {
+        let config = ServerSideEncryptionConfiguration::from(&SseKMS::new("my-key", None));
+        let xml = config.to_xml().unwrap();
+        assert_eq!(
+            xml,
+            "<ServerSideEncryptionConfiguration><Rule><ApplyServerSideEncryptionByDefault>\
+            <SSEAlgorithm>aws:kms</SSEAlgorithm><KMSMasterKeyID>my-key</KMSMasterKeyID>\
+            </ApplyServerSideEncryptionByDefault></Rule></ServerSideEncryptionConfiguration>"
+        );
+        assert_eq!(
+            ServerSideEncryptionConfiguration::from_xml(xml).unwrap(),
+            config
+        );
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ServerSideEncryptionConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Rule><ApplyServerSideEncryptionByDefault><SSEAlgorithm>AES256</SSEAlgorithm></ApplyServerSideEncryptionByDefault></Rule>
+        </ServerSideEncryptionConfiguration>"#;
+        let config = ServerSideEncryptionConfiguration::from_xml(xml.to_string()).unwrap();
+        assert_eq!(
+            config,
+            ServerSideEncryptionConfiguration::from(&SseS3::new())
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::Tag;
+use crate::time::UtcTime;
+
+fn deserialize_option_date<'de, D>(deserializer: D) -> Result<Option<UtcTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::time::deserialize_with_str(deserializer).map(Some)
+}
+
+/// Lifecycle configuration of a bucket, a set of up to 1,000 rules.
+///
+/// See `get_bucket_lifecycle` and `set_bucket_lifecycle` API.
+/// ## Example
+/// ```rust
+/// use minio_rsc::datatype::{Expiration, Filter, LifecycleConfiguration, LifecycleRule};
+/// // delete objects under `logs/` after 30 days.
+/// let config = LifecycleConfiguration::new(vec![
+///     LifecycleRule::new("expire-logs")
+///         .filter(Filter::prefix("logs/"))
+///         .expiration(Expiration::days(30)),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LifecycleConfiguration {
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<LifecycleRule>,
+}
+
+impl LifecycleConfiguration {
+    pub fn new(rules: Vec<LifecycleRule>) -> Self {
+        Self { rules }
+    }
+}
+
+/// A lifecycle rule of [LifecycleConfiguration].
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LifecycleRule {
+    /// Unique identifier of the rule, up to 255 characters.
+    #[serde(rename = "ID")]
+    pub id: Option<String>,
+    /// Valid Values: Enabled | Disabled
+    pub status: RuleStatus,
+    /// The objects the rule applies to, all objects of the bucket if empty.
+    #[serde(default)]
+    pub filter: Filter,
+    pub expiration: Option<Expiration>,
+    pub noncurrent_version_expiration: Option<NoncurrentVersionExpiration>,
+    #[serde(rename = "Transition", default)]
+    pub transitions: Vec<Transition>,
+    pub abort_incomplete_multipart_upload: Option<AbortIncompleteMultipartUpload>,
+}
+
+impl LifecycleRule {
+    /// Create an enabled rule applying to all objects of the bucket.
+    pub fn new<S: Into<String>>(id: S) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn status(mut self, status: RuleStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn expiration(mut self, expiration: Expiration) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    pub fn noncurrent_version_expiration(
+        mut self,
+        expiration: NoncurrentVersionExpiration,
+    ) -> Self {
+        self.noncurrent_version_expiration = Some(expiration);
+        self
+    }
+
+    /// Add a transition of the rule.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transitions.push(transition);
+        self
+    }
+
+    /// Abort incomplete multipart uploads `days` after initiation.
+    pub fn abort_incomplete_multipart_upload(mut self, days: u32) -> Self {
+        self.abort_incomplete_multipart_upload = Some(AbortIncompleteMultipartUpload {
+            days_after_initiation: days,
+        });
+        self
+    }
+}
+
+/// Filter of [LifecycleRule], set only one of the fields.
+/// Use `and` to combine multiple conditions.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Filter {
+    pub prefix: Option<String>,
+    pub tag: Option<Tag>,
+    /// Minimum object size in bytes to which the rule applies.
+    pub object_size_greater_than: Option<u64>,
+    /// Maximum object size in bytes to which the rule applies.
+    pub object_size_less_than: Option<u64>,
+    pub and: Option<LifecycleRuleAndOperator>,
+}
+
+impl Filter {
+    /// Filter objects with key `prefix`.
+    pub fn prefix<S: Into<String>>(prefix: S) -> Self {
+        Self {
+            prefix: Some(prefix.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Filter objects with the tag.
+    pub fn tag<K: Into<String>, V: Into<String>>(key: K, value: V) -> Self {
+        Self {
+            tag: Some(Tag {
+                key: key.into(),
+                value: value.into(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Filter objects matching all conditions of `and`.
+    pub fn and(and: LifecycleRuleAndOperator) -> Self {
+        Self {
+            and: Some(and),
+            ..Default::default()
+        }
+    }
+}
+
+/// Conditions of [Filter] which must all match.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LifecycleRuleAndOperator {
+    pub prefix: Option<String>,
+    #[serde(rename = "Tag", default)]
+    pub tags: Vec<Tag>,
+    pub object_size_greater_than: Option<u64>,
+    pub object_size_less_than: Option<u64>,
+}
+
+/// Expiration of current object versions, set either `date` or `days`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Expiration {
+    /// The date objects expire, must be at midnight UTC.
+    #[serde(default, deserialize_with = "deserialize_option_date")]
+    pub date: Option<UtcTime>,
+    /// Number of days after creation that objects expire.
+    pub days: Option<u32>,
+    /// Remove expired object delete markers, can't be set with `date` or `days`.
+    pub expired_object_delete_marker: Option<bool>,
+}
+
+impl Expiration {
+    /// Expire objects `days` after creation.
+    pub fn days(days: u32) -> Self {
+        Self {
+            days: Some(days),
+            ..Default::default()
+        }
+    }
+
+    /// Expire objects at `date`.
+    pub fn date(date: UtcTime) -> Self {
+        Self {
+            date: Some(date),
+            ..Default::default()
+        }
+    }
+}
+
+/// Expiration of noncurrent object versions.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NoncurrentVersionExpiration {
+    /// Number of days after becoming noncurrent that versions expire.
+    pub noncurrent_days: Option<u32>,
+    /// Number of the newest noncurrent versions to retain.
+    pub newer_noncurrent_versions: Option<u32>,
+}
+
+/// Transition of objects to another storage class, set either `date` or `days`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Transition {
+    #[serde(default, deserialize_with = "deserialize_option_date")]
+    pub date: Option<UtcTime>,
+    pub days: Option<u32>,
+    /// The storage class, the name of a remote tier on MinIO.
+    pub storage_class: String,
+}
+
+impl Transition {
+    /// Transition objects to `storage_class` `days` after creation.
+    pub fn days<S: Into<String>>(days: u32, storage_class: S) -> Self {
+        Self {
+            date: None,
+            days: Some(days),
+            storage_class: storage_class.into(),
+        }
+    }
+}
+
+/// Days after initiation that incomplete multipart uploads are aborted.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AbortIncompleteMultipartUpload {
+    pub days_after_initiation: u32,
+}
+
+/// Whether a rule is applied.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RuleStatus {
+    #[default]
+    Enabled,
+    Disabled,
+}
+
+impl From<bool> for RuleStatus {
+    fn from(enabled: bool) -> Self {
+        if enabled {
+            Self::Enabled
+        } else {
+            Self::Disabled
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatype::{FromXml, ToXml};
+
+    #[test]
+    fn test_lifecycle_configuration() {
+        let config = LifecycleConfiguration::new(vec![
+            LifecycleRule::new("expire-logs")
+                .filter(Filter::prefix("logs/"))
+                .expiration(Expiration::days(30))
+                .abort_incomplete_multipart_upload(7),
+            LifecycleRule::new("archive")
+                .status(RuleStatus::Disabled)
+                .filter(Filter::and(LifecycleRuleAndOperator {
+                    prefix: Some("data/".to_string()),
+                    tags: vec![
+                        Tag {
+                            key: "k1".to_string(),
+                            value: "v1".to_string(),
+                        },
+                        Tag {
+                            key: "k2".to_string(),
+                            value: "v2".to_string(),
+                        },
+                    ],
+                    object_size_greater_than: Some(1024),
+                    object_size_less_than: None,
+                }))
+                .transition(Transition::days(10, "WARM"))
+                .noncurrent_version_expiration(NoncurrentVersionExpiration {
+                    noncurrent_days: Some(3),
+                    newer_noncurrent_versions: None,
+                }),
+            LifecycleRule::new("all"),
+        ]);
+        let xml = config.to_xml().unwrap();
+        assert!(xml.starts_with("<LifecycleConfiguration><Rule><ID>expire-logs</ID>"));
+        assert!(xml.contains("<Filter><Prefix>logs/</Prefix></Filter>"));
+        assert!(xml.contains("<Expiration><Days>30</Days></Expiration>"));
+        assert!(xml.contains("<Tag><Key>k1</Key><Value>v1</Value></Tag><Tag><Key>k2</Key>"));
+        assert!(xml.contains("<Transition><Days>10</Days><StorageClass>WARM</StorageClass>"));
+        assert!(xml.contains("<ID>all</ID><Status>Enabled</Status><Filter></Filter></Rule>"));
+        assert_eq!(LifecycleConfiguration::from_xml(xml).unwrap(), config);
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <LifecycleConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Rule>
+                <ID>expire</ID>
+                <Status>Enabled</Status>
+                <Filter><Tag><Key>k</Key><Value>v</Value></Tag></Filter>
+                <Expiration><Date>2030-01-01T00:00:00Z</Date></Expiration>
+            </Rule>
+        </LifecycleConfiguration>"#;
+        let config = LifecycleConfiguration::from_xml(xml.to_string()).unwrap();
+        assert_eq!(config.rules.len(), 1);
+        let rule = &config.rules[0];
+        assert_eq!(rule.filter.tag.as_ref().unwrap().value, "v");
+        let date = rule.expiration.as_ref().unwrap().date.unwrap();
+        assert_eq!(date.format_time(), "2030-01-01T00:00:00.000Z");
+        assert!(rule.transitions.is_empty());
+    }
+}
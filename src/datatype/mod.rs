@@ -1,7 +1,19 @@
 //! Data types
 
+mod cors;
+mod encryption;
+mod lifecycle;
+mod notification;
+mod policy;
+mod replication;
 mod select_object_content;
 
+pub use cors::*;
+pub use encryption::*;
+pub use lifecycle::*;
+pub use notification::*;
+pub use policy::*;
+pub use replication::*;
 pub use select_object_content::*;
 
 use serde::{Deserialize, Serialize};
@@ -31,9 +43,17 @@ impl_xmlself!(
     InitiateMultipartUploadResult
     ListMultipartUploadsResult
     CopyPartResult
+    Delete
+    DeleteResult
     ListPartsResult
     ListAllMyBucketsResult
     ListBucketResult
+    ListVersionsResult
+    LifecycleConfiguration
+    NotificationConfiguration
+    ServerSideEncryptionConfiguration
+    ReplicationConfiguration
+    CorsConfiguration
 );
 
 pub trait ToXml {
@@ -131,6 +151,38 @@ pub struct DefaultRetention {
     pub years: Option<usize>,
 }
 
+/// Object representation of request XML of `delete_objects` API.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Delete {
+    /// Enables quiet mode, the response includes only keys where the delete action encountered an error.
+    pub quiet: bool,
+    #[serde(rename = "Object", default)]
+    pub objects: Vec<ObjectIdentifier>,
+}
+
+/// Container for an error of a key in `delete_objects` API.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteError {
+    pub key: String,
+    pub version_id: Option<String>,
+    pub code: String,
+    pub message: String,
+}
+
+/// A delete marker of a versioned object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteMarker {
+    /// Specifies whether the object is (true) or is not (false) the latest version of an object.
+    pub is_latest: bool,
+    pub key: String,
+    pub last_modified: String,
+    pub owner: Option<Owner>,
+    pub version_id: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub(crate) struct InitiateMultipartUploadResult {
@@ -139,6 +191,68 @@ pub(crate) struct InitiateMultipartUploadResult {
     pub upload_id: String,
 }
 
+/// Object representation of response XML of `delete_objects` API.
+///
+/// **Note**: `Deleted` and `Error` elements may be interleaved in the response,
+/// they are collected into `deleted` and `errors` respectively.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteResult {
+    #[serde(rename = "Deleted")]
+    pub deleted: Vec<DeletedObject>,
+    #[serde(rename = "Error")]
+    pub errors: Vec<DeleteError>,
+}
+
+impl<'de> Deserialize<'de> for DeleteResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct DeleteResultVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DeleteResultVisitor {
+            type Value = DeleteResult;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("struct DeleteResult")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut result = DeleteResult::default();
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "Deleted" => result.deleted.push(map.next_value()?),
+                        "Error" => result.errors.push(map.next_value()?),
+                        _ => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_struct("DeleteResult", &[], DeleteResultVisitor)
+    }
+}
+
+/// Container for a key successfully deleted in `delete_objects` API.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeletedObject {
+    pub key: String,
+    pub version_id: Option<String>,
+    /// Indicates whether the specified object version that was permanently deleted was (true)
+    /// or was not (false) a delete marker before deletion.
+    pub delete_marker: Option<bool>,
+    /// The version ID of the delete marker created as a result of the DELETE operation.
+    pub delete_marker_version_id: Option<String>,
+}
+
 /// Container element that identifies who initiated the multipart upload.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
@@ -221,6 +335,79 @@ pub struct ListPartsResult {
     pub owner: Owner,
 }
 
+/// Object representation of response XML of `list_object_versions` API.
+///
+/// **Note**: `Version` and `DeleteMarker` elements are interleaved in the response,
+/// they are collected into `versions` and `delete_markers` respectively.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListVersionsResult {
+    pub name: String,
+    pub prefix: String,
+    pub key_marker: String,
+    pub version_id_marker: String,
+    pub next_key_marker: String,
+    pub next_version_id_marker: String,
+    pub max_keys: usize,
+    pub delimiter: String,
+    pub is_truncated: bool,
+    #[serde(rename = "Version")]
+    pub versions: Vec<ObjectVersion>,
+    #[serde(rename = "DeleteMarker")]
+    pub delete_markers: Vec<DeleteMarker>,
+    pub common_prefixes: Vec<CommonPrefix>,
+    pub encoding_type: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for ListVersionsResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ListVersionsResultVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ListVersionsResultVisitor {
+            type Value = ListVersionsResult;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("struct ListVersionsResult")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut result = ListVersionsResult::default();
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "Name" => result.name = map.next_value()?,
+                        "Prefix" => result.prefix = map.next_value()?,
+                        "KeyMarker" => result.key_marker = map.next_value()?,
+                        "VersionIdMarker" => result.version_id_marker = map.next_value()?,
+                        "NextKeyMarker" => result.next_key_marker = map.next_value()?,
+                        "NextVersionIdMarker" => {
+                            result.next_version_id_marker = map.next_value()?
+                        }
+                        "MaxKeys" => result.max_keys = map.next_value()?,
+                        "Delimiter" => result.delimiter = map.next_value()?,
+                        "IsTruncated" => result.is_truncated = map.next_value()?,
+                        "Version" => result.versions.push(map.next_value()?),
+                        "DeleteMarker" => result.delete_markers.push(map.next_value()?),
+                        "CommonPrefixes" => result.common_prefixes.push(map.next_value()?),
+                        "EncodingType" => result.encoding_type = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_struct("ListVersionsResult", &[], ListVersionsResultVisitor)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct MultipartUpload {
@@ -265,6 +452,30 @@ pub struct ObjectLockConfiguration {
     pub rule: Option<ObjectLockRule>,
 }
 
+/// Object identifier of `delete_objects` API.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectIdentifier {
+    pub key: String,
+    pub version_id: Option<String>,
+}
+
+/// A version of a versioned object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectVersion {
+    pub e_tag: String,
+    /// Specifies whether the object is (true) or is not (false) the latest version of an object.
+    pub is_latest: bool,
+    pub key: String,
+    pub last_modified: String,
+    pub owner: Option<Owner>,
+    pub size: u64,
+    pub storage_class: String,
+    pub version_id: String,
+    pub checksum_algorithm: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Owner {
@@ -289,21 +500,6 @@ pub struct Progress {
     pub bytes_scanned: u64,
 }
 
-/// A container for replication rules. You can add up to 1,000 rules. The maximum size of a replication configuration is 2 MB.
-#[derive(Debug, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "PascalCase")]
-pub struct ReplicationConfiguration {
-    pub role: String,
-    #[serde(rename = "Rule", default)]
-    pub rules: Vec<ReplicationRule>,
-}
-
-#[derive(Debug, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "PascalCase")]
-pub struct ReplicationRule {
-    pub role: String,
-}
-
 /// Object representation of request XML of `put_object_retention` API
 /// and response XML of `get_object_retention` API.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -326,7 +522,7 @@ pub struct Stats {
 }
 
 /// A container of a key value name pair.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Tag {
     pub key: String,
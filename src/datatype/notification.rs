@@ -0,0 +1,311 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::time::UtcTime;
+
+/// Notification configuration of a bucket,
+/// publishing bucket events to the targets configured on the server.
+///
+/// See `get_bucket_notification` and `set_bucket_notification` API.
+/// ## Example
+/// ```rust
+/// use minio_rsc::datatype::{NotificationConfiguration, QueueConfiguration};
+/// // publish the uploaded `.jpg` objects under `images/` to a webhook target.
+/// let config = NotificationConfiguration {
+///     queue_configurations: vec![QueueConfiguration::new(
+///         "arn:minio:sqs::primary:webhook",
+///         vec!["s3:ObjectCreated:*".to_string()],
+///     )
+///     .prefix("images/")
+///     .suffix(".jpg")],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NotificationConfiguration {
+    #[serde(rename = "QueueConfiguration", default)]
+    pub queue_configurations: Vec<QueueConfiguration>,
+    #[serde(rename = "TopicConfiguration", default)]
+    pub topic_configurations: Vec<TopicConfiguration>,
+    #[serde(rename = "CloudFunctionConfiguration", default)]
+    pub cloud_function_configurations: Vec<CloudFunctionConfiguration>,
+}
+
+impl NotificationConfiguration {
+    /// Returns `true` if there is no configuration.
+    pub fn is_empty(&self) -> bool {
+        self.queue_configurations.is_empty()
+            && self.topic_configurations.is_empty()
+            && self.cloud_function_configurations.is_empty()
+    }
+}
+
+macro_rules! impl_notification_config {
+    ($name:ident, $arn:ident) => {
+        impl $name {
+            #[doc = concat!("Publish `events` to the target `", stringify!($arn), "` ARN.")]
+            pub fn new<S: Into<String>>($arn: S, events: Vec<String>) -> Self {
+                Self {
+                    id: None,
+                    $arn: $arn.into(),
+                    events,
+                    filter: None,
+                }
+            }
+
+            pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+                self.id = Some(id.into());
+                self
+            }
+
+            /// Only publish events of objects with key `prefix`.
+            pub fn prefix<S: Into<String>>(self, prefix: S) -> Self {
+                self.filter_rule("prefix", prefix)
+            }
+
+            /// Only publish events of objects with key `suffix`.
+            pub fn suffix<S: Into<String>>(self, suffix: S) -> Self {
+                self.filter_rule("suffix", suffix)
+            }
+
+            fn filter_rule<S: Into<String>>(mut self, name: &str, value: S) -> Self {
+                let filter = self.filter.get_or_insert_with(Default::default);
+                filter.s3_key.filter_rules.push(FilterRule {
+                    name: name.to_string(),
+                    value: value.into(),
+                });
+                self
+            }
+        }
+    };
+}
+
+/// Publish events to a queue target, like webhook, AMQP or Kafka on MinIO.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QueueConfiguration {
+    #[serde(rename = "Id")]
+    pub id: Option<String>,
+    /// ARN of the target, like `arn:minio:sqs::primary:webhook`.
+    pub queue: String,
+    /// Events to publish, like `s3:ObjectCreated:*`.
+    #[serde(rename = "Event", default)]
+    pub events: Vec<String>,
+    pub filter: Option<NotificationFilter>,
+}
+
+impl_notification_config!(QueueConfiguration, queue);
+
+/// Publish events to a topic target.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TopicConfiguration {
+    #[serde(rename = "Id")]
+    pub id: Option<String>,
+    /// ARN of the target.
+    pub topic: String,
+    /// Events to publish, like `s3:ObjectCreated:*`.
+    #[serde(rename = "Event", default)]
+    pub events: Vec<String>,
+    pub filter: Option<NotificationFilter>,
+}
+
+impl_notification_config!(TopicConfiguration, topic);
+
+/// Publish events to a lambda function target.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CloudFunctionConfiguration {
+    #[serde(rename = "Id")]
+    pub id: Option<String>,
+    /// ARN of the target, like `arn:minio:lambda::primary:webhook`.
+    pub cloud_function: String,
+    /// Events to publish, like `s3:ObjectCreated:*`.
+    #[serde(rename = "Event", default)]
+    pub events: Vec<String>,
+    pub filter: Option<NotificationFilter>,
+}
+
+impl_notification_config!(CloudFunctionConfiguration, cloud_function);
+
+/// Filter of the object keys whose events are published.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NotificationFilter {
+    #[serde(rename = "S3Key", default)]
+    pub s3_key: S3Key,
+}
+
+/// Container of the key filter rules.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct S3Key {
+    #[serde(rename = "FilterRule", default)]
+    pub filter_rules: Vec<FilterRule>,
+}
+
+/// Key filter rule, the `name` is `prefix` or `suffix`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FilterRule {
+    pub name: String,
+    pub value: String,
+}
+
+/// An S3 event record, received by `listen_bucket_notification`.
+///
+/// Note: the object key is URL-encoded.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NotificationRecord {
+    pub event_version: String,
+    pub event_source: String,
+    pub aws_region: String,
+    pub event_time: UtcTime,
+    /// The event type, like `s3:ObjectCreated:Put`.
+    pub event_name: String,
+    pub user_identity: EventIdentity,
+    pub request_parameters: HashMap<String, String>,
+    pub response_elements: HashMap<String, String>,
+    pub s3: EventS3Entity,
+    pub source: EventSource,
+}
+
+/// The user who caused the event.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventIdentity {
+    pub principal_id: String,
+}
+
+/// The bucket and object of the event.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventS3Entity {
+    pub s3_schema_version: String,
+    pub configuration_id: String,
+    pub bucket: EventBucket,
+    pub object: EventObject,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventBucket {
+    pub name: String,
+    pub owner_identity: EventIdentity,
+    pub arn: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventObject {
+    /// The URL-encoded object key.
+    pub key: String,
+    pub size: Option<u64>,
+    pub e_tag: Option<String>,
+    pub content_type: Option<String>,
+    pub user_metadata: HashMap<String, String>,
+    pub version_id: Option<String>,
+    pub sequencer: String,
+}
+
+/// The client which caused the event.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventSource {
+    pub host: String,
+    pub port: String,
+    pub user_agent: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatype::{FromXml, ToXml};
+
+    #[test]
+    fn test_notification_configuration() {
+        let config = NotificationConfiguration {
+            queue_configurations: vec![QueueConfiguration::new(
+                "arn:minio:sqs::primary:webhook",
+                vec![
+                    "s3:ObjectCreated:*".to_string(),
+                    "s3:ObjectRemoved:*".to_string(),
+                ],
+            )
+            .id("1")
+            .prefix("images/")
+            .suffix(".jpg")],
+            cloud_function_configurations: vec![CloudFunctionConfiguration::new(
+                "arn:minio:lambda::primary:webhook",
+                vec!["s3:ObjectAccessed:Get".to_string()],
+            )],
+            ..Default::default()
+        };
+        let xml = config.to_xml().unwrap();
+        assert_eq!(
+            xml,
+            "<NotificationConfiguration><QueueConfiguration><Id>1</Id>\
+            <Queue>arn:minio:sqs::primary:webhook</Queue>\
+            <Event>s3:ObjectCreated:*</Event><Event>s3:ObjectRemoved:*</Event>\
+            <Filter><S3Key><FilterRule><Name>prefix</Name><Value>images/</Value></FilterRule>\
+            <FilterRule><Name>suffix</Name><Value>.jpg</Value></FilterRule></S3Key></Filter>\
+            </QueueConfiguration><CloudFunctionConfiguration>\
+            <CloudFunction>arn:minio:lambda::primary:webhook</CloudFunction>\
+            <Event>s3:ObjectAccessed:Get</Event></CloudFunctionConfiguration>\
+            </NotificationConfiguration>"
+        );
+        assert_eq!(NotificationConfiguration::from_xml(xml).unwrap(), config);
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <NotificationConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"></NotificationConfiguration>"#;
+        let config = NotificationConfiguration::from_xml(xml.to_string()).unwrap();
+        assert!(config.is_empty());
+        assert_eq!(
+            NotificationConfiguration::default().to_xml().unwrap(),
+            "<NotificationConfiguration></NotificationConfiguration>"
+        );
+    }
+
+    #[test]
+    fn test_notification_record() {
+        let json = r#"{
+            "eventVersion": "2.0",
+            "eventSource": "minio:s3",
+            "awsRegion": "",
+            "eventTime": "2023-09-10T08:26:43.296Z",
+            "eventName": "s3:ObjectCreated:Put",
+            "userIdentity": {"principalId": "minioadmin"},
+            "requestParameters": {"principalId": "minioadmin", "region": "", "sourceIPAddress": "127.0.0.1"},
+            "responseElements": {"x-amz-request-id": "1783C5CC2AC8D1C6"},
+            "s3": {
+                "s3SchemaVersion": "1.0",
+                "configurationId": "Config",
+                "bucket": {
+                    "name": "bucket",
+                    "ownerIdentity": {"principalId": "minioadmin"},
+                    "arn": "arn:aws:s3:::bucket"
+                },
+                "object": {
+                    "key": "images%2Fa.jpg",
+                    "size": 1024,
+                    "eTag": "d41d8cd98f00b204e9800998ecf8427e",
+                    "contentType": "image/jpeg",
+                    "userMetadata": {"content-type": "image/jpeg"},
+                    "sequencer": "1783C5CC2AFC1E10"
+                }
+            },
+            "source": {"host": "127.0.0.1", "port": "", "userAgent": "MinIO (linux; amd64)"}
+        }"#;
+        let record: NotificationRecord = serde_json::from_str(json).unwrap();
+        assert_eq!(record.event_name, "s3:ObjectCreated:Put");
+        assert_eq!(record.event_time.format_time(), "2023-09-10T08:26:43.296Z");
+        assert_eq!(record.s3.bucket.name, "bucket");
+        assert_eq!(record.s3.object.key, "images%2Fa.jpg");
+        assert_eq!(record.s3.object.size, Some(1024));
+        assert!(record.s3.object.version_id.is_none());
+        assert_eq!(record.source.user_agent, "MinIO (linux; amd64)");
+    }
+}
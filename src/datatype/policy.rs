@@ -0,0 +1,258 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Version of the policy language.
+pub const POLICY_VERSION: &str = "2012-10-17";
+
+const BUCKET_ACTIONS: [&str; 2] = ["s3:GetBucketLocation", "s3:ListBucketMultipartUploads"];
+const OBJECT_READ_ACTIONS: [&str; 1] = ["s3:GetObject"];
+const OBJECT_WRITE_ACTIONS: [&str; 4] = [
+    "s3:AbortMultipartUpload",
+    "s3:DeleteObject",
+    "s3:ListMultipartUploadParts",
+    "s3:PutObject",
+];
+
+/// Deserialize a string or an array of strings.
+fn string_or_vec<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrVec {
+        One(String),
+        Many(Vec<String>),
+    }
+    Ok(match StringOrVec::deserialize(deserializer)? {
+        StringOrVec::One(s) => vec![s],
+        StringOrVec::Many(v) => v,
+    })
+}
+
+/// Bucket policy, a JSON document of access permissions.
+///
+/// See `get_bucket_policy` and `set_bucket_policy` API.
+/// ## Example
+/// ```rust
+/// use minio_rsc::datatype::{PolicyDocument, Statement, Effect, Principal};
+/// // anonymous users can download objects under `public/`.
+/// let policy = PolicyDocument::read_only("bucket", "public/");
+/// // custom policy
+/// let policy = PolicyDocument::new(vec![Statement::new(
+///     Effect::Deny,
+///     Principal::any(),
+///     vec!["s3:DeleteObject".to_string()],
+///     vec!["arn:aws:s3:::bucket/*".to_string()],
+/// )]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PolicyDocument {
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Statement", default)]
+    pub statements: Vec<Statement>,
+}
+
+impl PolicyDocument {
+    pub fn new(statements: Vec<Statement>) -> Self {
+        Self {
+            version: POLICY_VERSION.to_string(),
+            id: None,
+            statements,
+        }
+    }
+
+    /// Canned policy allowing anyone to list and download objects under `prefix`.
+    ///
+    /// Set `prefix` empty for the whole bucket.
+    pub fn read_only(bucket: &str, prefix: &str) -> Self {
+        Self::new(vec![
+            Statement::bucket(bucket, &BUCKET_ACTIONS[..1]),
+            Statement::list_bucket(bucket, prefix),
+            Statement::object(bucket, prefix, &OBJECT_READ_ACTIONS),
+        ])
+    }
+
+    /// Canned policy allowing anyone to upload and delete objects under `prefix`.
+    ///
+    /// Set `prefix` empty for the whole bucket.
+    pub fn write_only(bucket: &str, prefix: &str) -> Self {
+        Self::new(vec![
+            Statement::bucket(bucket, &BUCKET_ACTIONS),
+            Statement::object(bucket, prefix, &OBJECT_WRITE_ACTIONS),
+        ])
+    }
+
+    /// Canned policy allowing anyone to list, download, upload and delete objects under `prefix`.
+    ///
+    /// Set `prefix` empty for the whole bucket.
+    pub fn read_write(bucket: &str, prefix: &str) -> Self {
+        let actions = [OBJECT_READ_ACTIONS.as_slice(), &OBJECT_WRITE_ACTIONS].concat();
+        Self::new(vec![
+            Statement::bucket(bucket, &BUCKET_ACTIONS),
+            Statement::list_bucket(bucket, prefix),
+            Statement::object(bucket, prefix, &actions),
+        ])
+    }
+}
+
+/// Whether a statement allows or denies access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Effect {
+    Allow,
+    Deny,
+}
+
+/// The accounts, users or services a statement applies to,
+/// like `{"AWS": ["*"]}`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct Principal(pub BTreeMap<String, Vec<String>>);
+
+impl Principal {
+    /// All users, including anonymous users.
+    pub fn any() -> Self {
+        Self::aws(vec!["*".to_string()])
+    }
+
+    /// AWS accounts or users, by ARN.
+    pub fn aws(arns: Vec<String>) -> Self {
+        Self([("AWS".to_string(), arns)].into())
+    }
+}
+
+impl<'de> Deserialize<'de> for Principal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Values(#[serde(deserialize_with = "string_or_vec")] Vec<String>);
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum PrincipalRepr {
+            Any(String),
+            Map(BTreeMap<String, Values>),
+        }
+        Ok(match PrincipalRepr::deserialize(deserializer)? {
+            PrincipalRepr::Any(s) => Self::aws(vec![s]),
+            PrincipalRepr::Map(m) => Self(m.into_iter().map(|(k, v)| (k, v.0)).collect()),
+        })
+    }
+}
+
+/// A permission of [PolicyDocument].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Statement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    pub effect: Effect,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Principal>,
+    #[serde(default, deserialize_with = "string_or_vec")]
+    pub action: Vec<String>,
+    #[serde(default, deserialize_with = "string_or_vec")]
+    pub resource: Vec<String>,
+    /// Conditions of the statement, like `{"StringEquals": {"s3:prefix": ["public"]}}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<serde_json::Value>,
+}
+
+impl Statement {
+    pub fn new(
+        effect: Effect,
+        principal: Principal,
+        action: Vec<String>,
+        resource: Vec<String>,
+    ) -> Self {
+        Self {
+            sid: None,
+            effect,
+            principal: Some(principal),
+            action,
+            resource,
+            condition: None,
+        }
+    }
+
+    /// Allow anyone the bucket `actions`.
+    fn bucket(bucket: &str, actions: &[&str]) -> Self {
+        Self::new(
+            Effect::Allow,
+            Principal::any(),
+            actions.iter().map(|a| a.to_string()).collect(),
+            vec![format!("arn:aws:s3:::{}", bucket)],
+        )
+    }
+
+    /// Allow anyone to list objects under `prefix`.
+    fn list_bucket(bucket: &str, prefix: &str) -> Self {
+        let mut statement = Self::bucket(bucket, &["s3:ListBucket"]);
+        if !prefix.is_empty() {
+            statement.condition = Some(serde_json::json!({
+                "StringEquals": {"s3:prefix": [prefix]}
+            }));
+        }
+        statement
+    }
+
+    /// Allow anyone the object `actions` under `prefix`.
+    fn object(bucket: &str, prefix: &str, actions: &[&str]) -> Self {
+        Self::new(
+            Effect::Allow,
+            Principal::any(),
+            actions.iter().map(|a| a.to_string()).collect(),
+            vec![format!("arn:aws:s3:::{}/{}*", bucket, prefix)],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Effect, PolicyDocument, Principal};
+
+    #[test]
+    fn test_policy_document() {
+        let json = r#"{
+            "Version": "2012-10-17",
+            "Statement": [{
+                "Effect": "Allow",
+                "Principal": "*",
+                "Action": "s3:GetObject",
+                "Resource": ["arn:aws:s3:::bucket/public/*"]
+            }, {
+                "Sid": "deny",
+                "Effect": "Deny",
+                "Principal": {"AWS": "arn:aws:iam::123456789012:root"},
+                "Action": ["s3:DeleteObject", "s3:PutObject"],
+                "Resource": "arn:aws:s3:::bucket/*"
+            }]
+        }"#;
+        let policy: PolicyDocument = serde_json::from_str(json).unwrap();
+        assert_eq!(policy.statements.len(), 2);
+        assert_eq!(policy.statements[0].principal, Some(Principal::any()));
+        assert_eq!(policy.statements[0].action, vec!["s3:GetObject"]);
+        assert_eq!(policy.statements[1].effect, Effect::Deny);
+        assert_eq!(policy.statements[1].resource, vec!["arn:aws:s3:::bucket/*"]);
+
+        let policy = PolicyDocument::read_only("bucket", "public/");
+        let json = serde_json::to_string(&policy).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PolicyDocument>(&json).unwrap(),
+            policy
+        );
+        assert_eq!(
+            policy.statements[2].resource,
+            vec!["arn:aws:s3:::bucket/public/*"]
+        );
+        assert!(policy.statements[0].condition.is_none());
+        assert!(policy.statements[1].condition.is_some());
+        let policy = PolicyDocument::read_write("bucket", "");
+        assert!(policy.statements.iter().all(|s| s.condition.is_none()));
+    }
+}
@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+
+use super::{RuleStatus, Tag};
+
+/// A container for replication rules. You can add up to 1,000 rules. The maximum size of a replication configuration is 2 MB.
+///
+/// See `get_bucket_replication` and `set_bucket_replication` API.
+/// ## Example
+/// ```rust
+/// use minio_rsc::datatype::{ReplicationConfiguration, ReplicationFilter, ReplicationRule};
+/// // the ARN of the remote target, created by `mc admin bucket remote add`.
+/// let arn = "arn:minio:replication::c5be6b16-769d-432a-9ef1-4567081f3566:dest";
+/// let config = ReplicationConfiguration::new(vec![ReplicationRule::new("rule1", arn)
+///     .priority(1)
+///     .filter(ReplicationFilter::prefix("docs/"))
+///     .delete_marker_replication(true)
+///     .existing_object_replication(true)]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicationConfiguration {
+    /// The IAM role to replicate objects, empty on MinIO.
+    #[serde(default)]
+    pub role: String,
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<ReplicationRule>,
+}
+
+impl ReplicationConfiguration {
+    pub fn new(rules: Vec<ReplicationRule>) -> Self {
+        Self {
+            role: String::new(),
+            rules,
+        }
+    }
+}
+
+/// A replication rule of [ReplicationConfiguration].
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicationRule {
+    /// Unique identifier of the rule, up to 255 characters.
+    #[serde(rename = "ID")]
+    pub id: Option<String>,
+    /// Valid Values: Enabled | Disabled
+    pub status: RuleStatus,
+    /// The higher priority rule applies when multiple rules match an object.
+    #[serde(default)]
+    pub priority: u32,
+    #[serde(default)]
+    pub delete_marker_replication: DeleteMarkerReplication,
+    /// Whether to replicate the deletion of versions, a MinIO extension.
+    pub delete_replication: Option<DeleteReplication>,
+    pub destination: Destination,
+    /// The objects the rule applies to, all objects of the bucket if empty.
+    #[serde(default)]
+    pub filter: ReplicationFilter,
+    pub existing_object_replication: Option<ExistingObjectReplication>,
+}
+
+impl ReplicationRule {
+    /// Create an enabled rule replicating all objects to the `bucket` ARN,
+    /// without replicating deletions.
+    pub fn new<I: Into<String>, B: Into<String>>(id: I, bucket: B) -> Self {
+        Self {
+            id: Some(id.into()),
+            delete_replication: Some(DeleteReplication {
+                status: RuleStatus::Disabled,
+            }),
+            destination: Destination {
+                bucket: bucket.into(),
+                storage_class: None,
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn status(mut self, status: RuleStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn filter(mut self, filter: ReplicationFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Set the storage class of the replicated objects.
+    pub fn storage_class<S: Into<String>>(mut self, storage_class: S) -> Self {
+        self.destination.storage_class = Some(storage_class.into());
+        self
+    }
+
+    /// Whether to replicate delete markers.
+    pub fn delete_marker_replication(mut self, enabled: bool) -> Self {
+        self.delete_marker_replication.status = enabled.into();
+        self
+    }
+
+    /// Whether to replicate the deletion of versions.
+    pub fn delete_replication(mut self, enabled: bool) -> Self {
+        self.delete_replication = Some(DeleteReplication {
+            status: enabled.into(),
+        });
+        self
+    }
+
+    /// Whether to replicate the objects existing before the rule is created.
+    pub fn existing_object_replication(mut self, enabled: bool) -> Self {
+        self.existing_object_replication = Some(ExistingObjectReplication {
+            status: enabled.into(),
+        });
+        self
+    }
+}
+
+/// Whether delete markers are replicated.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteMarkerReplication {
+    pub status: RuleStatus,
+}
+
+impl Default for DeleteMarkerReplication {
+    fn default() -> Self {
+        Self {
+            status: RuleStatus::Disabled,
+        }
+    }
+}
+
+/// Whether the deletion of versions is replicated.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteReplication {
+    pub status: RuleStatus,
+}
+
+/// Whether the objects existing before the rule is created are replicated.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExistingObjectReplication {
+    pub status: RuleStatus,
+}
+
+/// The destination of replicated objects.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Destination {
+    /// The ARN of the destination bucket.
+    pub bucket: String,
+    pub storage_class: Option<String>,
+}
+
+/// Filter of [ReplicationRule], set only one of the fields.
+/// Use `and` to combine multiple conditions.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicationFilter {
+    pub prefix: Option<String>,
+    pub tag: Option<Tag>,
+    pub and: Option<ReplicationRuleAndOperator>,
+}
+
+impl ReplicationFilter {
+    /// Filter objects with key `prefix`.
+    pub fn prefix<S: Into<String>>(prefix: S) -> Self {
+        Self {
+            prefix: Some(prefix.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Filter objects with the tag.
+    pub fn tag<K: Into<String>, V: Into<String>>(key: K, value: V) -> Self {
+        Self {
+            tag: Some(Tag {
+                key: key.into(),
+                value: value.into(),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// Conditions of [ReplicationFilter] which must all match.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicationRuleAndOperator {
+    pub prefix: Option<String>,
+    #[serde(rename = "Tag", default)]
+    pub tags: Vec<Tag>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatype::{FromXml, ToXml};
+
+    #[test]
+    fn test_replication_configuration() {
+        let config = ReplicationConfiguration::new(vec![
+            ReplicationRule::new("rule1", "arn:minio:replication::id:dest")
+                .priority(2)
+                .filter(ReplicationFilter::prefix("docs/"))
+                .delete_marker_replication(true)
+                .existing_object_replication(true),
+            ReplicationRule::new("rule2", "arn:minio:replication::id:dest")
+                .priority(1)
+                .storage_class("STANDARD")
+                .filter(ReplicationFilter {
+                    and: Some(ReplicationRuleAndOperator {
+                        prefix: Some("images/".to_string()),
+                        tags: vec![Tag {
+                            key: "k".to_string(),
+                            value: "v".to_string(),
+                        }],
+                    }),
+                    ..Default::default()
+                }),
+        ]);
+        let xml = config.to_xml().unwrap();
+        assert!(xml.starts_with(
+            "<ReplicationConfiguration><Role></Role><Rule><ID>rule1</ID>\
+            <Status>Enabled</Status><Priority>2</Priority>\
+            <DeleteMarkerReplication><Status>Enabled</Status></DeleteMarkerReplication>\
+            <DeleteReplication><Status>Disabled</Status></DeleteReplication>\
+            <Destination><Bucket>arn:minio:replication::id:dest</Bucket></Destination>\
+            <Filter><Prefix>docs/</Prefix></Filter>\
+            <ExistingObjectReplication><Status>Enabled</Status></ExistingObjectReplication></Rule>"
+        ));
+        assert!(xml.contains("<StorageClass>STANDARD</StorageClass>"));
+        assert!(xml.contains("<And><Prefix>images/</Prefix><Tag><Key>k</Key>"));
+        assert_eq!(ReplicationConfiguration::from_xml(xml).unwrap(), config);
+    }
+}
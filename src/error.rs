@@ -84,6 +84,7 @@ pub struct S3Error {
     pub host_id: Option<String>,
     pub bucket_name: Option<String>,
     pub object_name: Option<String>,
+    pub region: Option<String>,
 }
 
 impl std::fmt::Display for S3Error {
@@ -133,6 +134,14 @@ pub enum Error {
     /// indicate the http response returned is not expected by S3.
     UnknownResponse(reqwest::Response),
 
+    /// indicate the object is not modified, S3 service returned `304 Not Modified`
+    /// for `If-None-Match` or `If-Modified-Since`.
+    NotModified,
+
+    /// indicate a precondition is not met, S3 service returned `412 Precondition Failed`
+    /// for `If-Match`, `If-Unmodified-Since`, or `If-None-Match` when writing.
+    PreconditionFailed,
+
     /// Message decoding failed in `select object content`.
     MessageDecodeError(String),
 
@@ -163,6 +172,8 @@ impl fmt::Display for Error {
             Error::S3Error(e) => write!(f, "{}", e),
             Error::HttpError(e) => write!(f, "{}", e),
             Error::UnknownResponse(e) => write!(f, "Unexpected HTTP responses, status: {}", e.status()),
+            Error::NotModified => write!(f, "Not Modified"),
+            Error::PreconditionFailed => write!(f, "At least one of the preconditions you specified did not hold"),
             Error::MessageDecodeError(e)=> write!(f, "{}", e),
             Error::SelectObejectError(e)=> write!(f, "{}", e),
             Error::IoError(e) => write!(f, "{}", e),
@@ -15,4 +15,7 @@ pub mod xml;
 pub use crate::client::Minio;
 pub use crate::credentials::Credentials;
 pub use crate::data::Data;
-pub use crate::signer::{presign_v4, sign_request_v4, sign_v4_authorization};
+pub use crate::signer::{
+    presign_v4, presign_v4_with_headers, presign_v4_with_token, sign_request_v4,
+    sign_v4_authorization,
+};
@@ -0,0 +1,152 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_mutex::Mutex;
+
+use super::{
+    AwsFileProvider, CredenticalFuture, IamAwsProvider, MinioConfigProvider, Provider,
+    StaticProvider,
+};
+use crate::Credentials;
+
+/// Credential provider that tries a list of providers in order.
+///
+/// The providers are fetched one by one, the first non-empty credentials are returned
+/// without fetching the rest, empty credentials are returned if all providers fail.
+/// The provider which returned credentials is fetched first next time,
+/// the chain is walked again only if it returns empty credentials.
+/// ## Example
+/// ```rust
+/// use minio_rsc::provider::{ChainProvider, MinioConfigProvider, StaticProvider};
+/// // env MinIO, env AWS, AWS shared credentials file, MinIO `mc` config file, IAM.
+/// let provider = ChainProvider::new();
+///
+/// let provider = ChainProvider::empty()
+///     .push(MinioConfigProvider::new().alias("local"))
+///     .push(StaticProvider::new("minio-access-key-test", "minio-secret-key-test", None));
+/// ```
+pub struct ChainProvider {
+    providers: Vec<Arc<Mutex<Box<dyn Provider>>>>,
+    current: Arc<AtomicUsize>,
+}
+
+impl ChainProvider {
+    /// Create the chain of env MinIO, env AWS, AWS shared credentials file,
+    /// MinIO `mc` config file and IAM role of instance metadata, same as [ChainProvider::default].
+    ///
+    /// IAM is the last one, as it requests the metadata service which is unreachable
+    /// outside of EC2 and containers.
+    pub fn new() -> Self {
+        let mut chain = Self::empty();
+        if let Some(provider) = StaticProvider::from_env() {
+            chain = chain.push(provider);
+        }
+        if let Some(provider) = StaticProvider::from_env_aws() {
+            chain = chain.push(provider);
+        }
+        chain
+            .push(AwsFileProvider::new())
+            .push(MinioConfigProvider::new())
+            .push(IamAwsProvider::new())
+    }
+
+    /// Create an empty chain.
+    pub fn empty() -> Self {
+        Self {
+            providers: Vec::new(),
+            current: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Append a provider to the end of the chain.
+    pub fn push<P: Provider + 'static>(mut self, provider: P) -> Self {
+        let provider: Box<dyn Provider> = Box::new(provider);
+        self.providers.push(Arc::new(Mutex::new(provider)));
+        self
+    }
+}
+
+impl Default for ChainProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Provider for ChainProvider {
+    fn fetct(&mut self) -> CredenticalFuture {
+        let providers = self.providers.clone();
+        let current = self.current.clone();
+        Box::pin(async move {
+            let first = current.load(Ordering::Acquire);
+            if let Some(provider) = providers.get(first) {
+                let cred = provider.lock().await.fetct().await;
+                if !cred.access_key().is_empty() {
+                    return cred;
+                }
+            }
+            for (index, provider) in providers.iter().enumerate() {
+                if index == first {
+                    continue;
+                }
+                let cred = provider.lock().await.fetct().await;
+                if !cred.access_key().is_empty() {
+                    current.store(index, Ordering::Release);
+                    return cred;
+                }
+            }
+            Credentials::new("", "", None, None)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::ChainProvider;
+    use crate::provider::{CredenticalFuture, Provider};
+    use crate::Credentials;
+
+    /// Returns `access_keys` in turn, the last one is repeated.
+    struct CountProvider {
+        access_keys: Vec<&'static str>,
+        count: Arc<AtomicUsize>,
+    }
+
+    impl Provider for CountProvider {
+        fn fetct(&mut self) -> CredenticalFuture {
+            let n = self.count.fetch_add(1, Ordering::SeqCst);
+            let access_key = self.access_keys[n.min(self.access_keys.len() - 1)];
+            let cred = Credentials::new(access_key, "sk", None, None);
+            Box::pin(async move { cred })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chain_provider() {
+        let counts: Vec<Arc<AtomicUsize>> = (0..3).map(|_| Default::default()).collect();
+        let keys = [vec![""], vec!["second", ""], vec!["third"]];
+        let mut chain = ChainProvider::empty();
+        for (access_keys, count) in keys.into_iter().zip(&counts) {
+            let count = count.clone();
+            chain = chain.push(CountProvider { access_keys, count });
+        }
+        assert_eq!(chain.fetct().await.access_key(), "second");
+        // the second returns empty credentials, walk the chain again.
+        assert_eq!(chain.fetct().await.access_key(), "third");
+        // only the third is fetched.
+        assert_eq!(chain.fetct().await.access_key(), "third");
+        let counts: Vec<usize> = counts.iter().map(|c| c.load(Ordering::SeqCst)).collect();
+        assert_eq!(counts, vec![2, 2, 2]);
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut chain = ChainProvider::empty().push(CountProvider {
+            access_keys: vec![""],
+            count: count.clone(),
+        });
+        assert!(chain.fetct().await.access_key().is_empty());
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert!(ChainProvider::empty().fetct().await.access_key().is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+use std::env;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::sts::{fetch_with_cache, CredentialsCache};
+use super::{CredenticalFuture, Provider};
+use crate::error::{Result, ValueError};
+use crate::Credentials;
+
+const DEFAULT_IMDS_ENDPOINT: &str = "http://169.254.169.254";
+const DEFAULT_ECS_ENDPOINT: &str = "http://169.254.170.2";
+const IMDS_TOKEN_TTL_SECONDS: &str = "21600";
+/// Delay of requesting the metadata service again after a failure, doubled on each failure,
+/// so that hosts without metadata service do not pay the connect timeout on every request.
+const MIN_RETRY_DELAY: Duration = Duration::from_secs(10);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(600);
+
+/// Credentials returned by instance metadata service or container credentials endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IamCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    token: Option<String>,
+    expiration: Option<DateTime<Utc>>,
+}
+
+impl From<IamCredentials> for Credentials {
+    fn from(creds: IamCredentials) -> Self {
+        Credentials::new(
+            creds.access_key_id,
+            creds.secret_access_key,
+            creds.token,
+            creds.expiration.map(|e| e.timestamp()),
+        )
+    }
+}
+
+/// Credential provider of IAM role of EC2 instance, ECS task or EKS pod.
+///
+/// Sources are tried in this order:
+/// 1. `AWS_CONTAINER_CREDENTIALS_RELATIVE_URI`, relative to `http://169.254.170.2`.
+/// 2. `AWS_CONTAINER_CREDENTIALS_FULL_URI`, with the `Authorization` header from
+///    `AWS_CONTAINER_AUTHORIZATION_TOKEN` or the file of `AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE`.
+/// 3. EC2 instance metadata service (IMDSv2) at `AWS_EC2_METADATA_SERVICE_ENDPOINT`
+///    or `http://169.254.169.254`, falling back to IMDSv1 if the token request is rejected.
+///
+/// The credentials are cached until shortly before `Expiration`.
+/// After a failure, the metadata service is not requested again for a growing delay,
+/// from 10 seconds up to 10 minutes.
+///
+/// The default http client has a connect timeout of 1 second and a request timeout of 5 seconds.
+/// ## Example
+/// ```rust
+/// use minio_rsc::provider::IamAwsProvider;
+/// let provider = IamAwsProvider::new();
+/// // use a local stub server
+/// let provider = IamAwsProvider::new().endpoint("http://127.0.0.1:8080");
+/// ```
+#[derive(Debug, Clone)]
+pub struct IamAwsProvider {
+    endpoint: Option<String>,
+    client: reqwest::Client,
+    cache: CredentialsCache,
+}
+
+impl IamAwsProvider {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(1))
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap_or_default();
+        Self {
+            endpoint: None,
+            client,
+            cache: CredentialsCache::with_retry_delay(MIN_RETRY_DELAY, MAX_RETRY_DELAY),
+        }
+    }
+
+    /// Override the base url of the metadata service,
+    /// used for IMDS and `AWS_CONTAINER_CREDENTIALS_RELATIVE_URI`.
+    pub fn endpoint<T: Into<String>>(mut self, endpoint: T) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Set custom http [reqwest::Client].
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Request new credentials from the metadata service, ignoring the cache.
+    pub async fn retrieve(&self) -> Result<Credentials> {
+        if let Ok(relative_uri) = env::var("AWS_CONTAINER_CREDENTIALS_RELATIVE_URI") {
+            let endpoint = self.endpoint.as_deref().unwrap_or(DEFAULT_ECS_ENDPOINT);
+            let url = format!("{}{}", endpoint.trim_end_matches('/'), relative_uri);
+            self.retrieve_ecs(&url, None).await
+        } else if let Ok(full_uri) = env::var("AWS_CONTAINER_CREDENTIALS_FULL_URI") {
+            let token = match env::var("AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE") {
+                Ok(file) => Some(std::fs::read_to_string(file)?.trim().to_string()),
+                Err(_) => env::var("AWS_CONTAINER_AUTHORIZATION_TOKEN").ok(),
+            };
+            self.retrieve_ecs(&full_uri, token).await
+        } else {
+            self.retrieve_imds().await
+        }
+    }
+
+    async fn retrieve_ecs(&self, url: &str, token: Option<String>) -> Result<Credentials> {
+        let mut req = self.client.get(url);
+        if let Some(token) = token {
+            req = req.header(reqwest::header::AUTHORIZATION, token);
+        }
+        let res = req.send().await?;
+        if !res.status().is_success() {
+            return Err(res.into());
+        }
+        let creds: IamCredentials = serde_json::from_slice(&res.bytes().await?)
+            .map_err(|e| ValueError::new(e.to_string()))?;
+        Ok(creds.into())
+    }
+
+    async fn retrieve_imds(&self) -> Result<Credentials> {
+        let endpoint = match &self.endpoint {
+            Some(endpoint) => endpoint.clone(),
+            None => env::var("AWS_EC2_METADATA_SERVICE_ENDPOINT")
+                .unwrap_or_else(|_| DEFAULT_IMDS_ENDPOINT.to_string()),
+        };
+        let endpoint = endpoint.trim_end_matches('/');
+        let res = self
+            .client
+            .put(format!("{}/latest/api/token", endpoint))
+            .header(
+                "X-aws-ec2-metadata-token-ttl-seconds",
+                IMDS_TOKEN_TTL_SECONDS,
+            )
+            .send()
+            .await;
+        let token = match res {
+            Ok(res) if res.status().is_success() => Some(res.text().await?),
+            // the service is unreachable, no need to try IMDSv1.
+            Err(err) if err.is_connect() => return Err(err.into()),
+            // IMDSv1 fallback, the token request is rejected or not supported.
+            _ => None,
+        };
+        let imds_get = |url: &str| {
+            let req = self.client.get(url);
+            match &token {
+                Some(token) => req.header("X-aws-ec2-metadata-token", token),
+                None => req,
+            }
+        };
+
+        let url = format!("{}/latest/meta-data/iam/security-credentials/", endpoint);
+        let res = imds_get(&url).send().await?;
+        if !res.status().is_success() {
+            return Err(res.into());
+        }
+        let text = res.text().await?;
+        let role = text
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .ok_or("no IAM role attached to the instance")?;
+
+        let res = imds_get(&format!("{}{}", url, role)).send().await?;
+        if !res.status().is_success() {
+            return Err(res.into());
+        }
+        let creds: IamCredentials = serde_json::from_slice(&res.bytes().await?)
+            .map_err(|e| ValueError::new(e.to_string()))?;
+        Ok(creds.into())
+    }
+}
+
+impl Default for IamAwsProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Provider for IamAwsProvider {
+    fn fetct(&mut self) -> CredenticalFuture {
+        let provider = self.clone();
+        fetch_with_cache(self.cache.clone(), async move { provider.retrieve().await })
+    }
+}
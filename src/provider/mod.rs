@@ -4,6 +4,17 @@ use std::{env, pin::Pin};
 
 use crate::Credentials;
 
+mod chain;
+mod file;
+mod iam;
+mod sts;
+pub use chain::ChainProvider;
+pub use file::{AwsFileProvider, MinioConfigProvider};
+pub use iam::IamAwsProvider;
+pub use sts::{
+    AssumeRoleProvider, ClientGrantsProvider, LdapIdentityProvider, WebIdentityProvider,
+};
+
 pub type CredenticalFuture = Pin<Box<dyn Future<Output = Credentials> + Send>>;
 
 /// define Credential retriever.
@@ -46,13 +57,13 @@ impl StaticProvider {
         } else {
             ak
         };
-        let sk = env::var("AWS_ACCESS_KEY_ID");
+        let sk = env::var("AWS_SECRET_ACCESS_KEY");
         let sk = if !sk.is_ok() {
-            env::var("AWS_ACCESS_KEY")
+            env::var("AWS_SECRET_KEY")
         } else {
             sk
         };
-        if let (Ok(ak), Ok(sk), st) = (ak, sk, env::var("MINIO_SESSION_TOKEN")) {
+        if let (Ok(ak), Ok(sk), st) = (ak, sk, env::var("AWS_SESSION_TOKEN")) {
             Some(Self::new(ak, sk, st.ok()))
         } else {
             None
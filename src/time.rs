@@ -44,6 +44,14 @@ impl UtcTime {
     pub fn aws_format_date(&self) -> String {
         self.0.format("%Y%m%d").to_string()
     }
+
+    /// format date to HTTP date, like `Sun, 10 Sep 2023 08:26:43 GMT`
+    ///
+    /// Used in conditional headers, such as `If-Modified-Since`.
+    #[inline]
+    pub fn http_format_time(&self) -> String {
+        self.0.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+    }
 }
 
 impl From<DateTime<Utc>> for UtcTime {
@@ -113,9 +113,21 @@ pub fn trim_bytes(b: &[u8]) -> &[u8] {
     }
 }
 
+/// Get region from the `LocationConstraint` response of `GetBucketLocation`.
+///
+/// Empty location means `us-east-1`, and legacy `EU` means `eu-west-1`.
+pub fn parse_location_constraint(text: &str) -> crate::xml::error::Result<String> {
+    let location: String = crate::xml::de::from_str(text)?;
+    Ok(match location.as_str() {
+        "" => "us-east-1".to_string(),
+        "EU" => "eu-west-1".to_string(),
+        _ => location,
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::utils::{is_urlencoded, trim_bytes};
+    use crate::utils::{is_urlencoded, parse_location_constraint, trim_bytes};
 
     use super::check_bucket_name;
     #[test]
@@ -137,4 +149,20 @@ mod tests {
     fn test_trim_bytes() {
         assert_eq!(trim_bytes(" hello \n".as_bytes()), "hello".as_bytes());
     }
+
+    #[test]
+    fn test_parse_location_constraint() {
+        let xmlns = r#"xmlns="http://s3.amazonaws.com/doc/2006-03-01/""#;
+        let text = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><LocationConstraint {xmlns}>eu-central-1</LocationConstraint>"#
+        );
+        assert_eq!(parse_location_constraint(&text).unwrap(), "eu-central-1");
+        let text = format!(r#"<LocationConstraint {xmlns}/>"#);
+        assert_eq!(parse_location_constraint(&text).unwrap(), "us-east-1");
+        let text = format!(r#"<LocationConstraint {xmlns}></LocationConstraint>"#);
+        assert_eq!(parse_location_constraint(&text).unwrap(), "us-east-1");
+        let text = "<LocationConstraint>EU</LocationConstraint>";
+        assert_eq!(parse_location_constraint(text).unwrap(), "eu-west-1");
+        assert!(parse_location_constraint("").is_err());
+    }
 }
@@ -32,6 +32,7 @@ macro_rules! deserialize_type {
         where
             V: serde::de::Visitor<'de>,
         {
+            self.root_tag()?;
             let tag = self.top_tag()?;
             let result = visitor.$visit(tag.content().parse()?);
             if result.is_ok() {
@@ -85,10 +86,58 @@ impl Event {
         String::from_utf8_lossy(&self.value)
     }
 
+    /// Text content of the tag, with the XML entities unescaped.
     #[inline]
     fn content(&self) -> std::borrow::Cow<'_, str> {
-        String::from_utf8_lossy(&self.content)
+        let content = String::from_utf8_lossy(&self.content);
+        if content.contains('&') {
+            unescape(&content).into()
+        } else {
+            content
+        }
+    }
+}
+
+/// Unescape the predefined entities and the character references like `&#13;` or `&#x0D;`.
+///
+/// Unknown entities are kept as is.
+fn unescape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let unescaped = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                entity => {
+                    let code = if let Some(hex) = entity.strip_prefix("#x") {
+                        u32::from_str_radix(hex, 16).ok()?
+                    } else {
+                        entity.strip_prefix('#')?.parse().ok()?
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+        match unescaped {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
     }
+    result.push_str(rest);
+    result
 }
 
 struct Deserializer<R: Read> {
@@ -117,6 +166,21 @@ impl<R: Read> Deserializer<R> {
         }
     }
 
+    /// Push the root tag if nothing has been read, so that a primitive like `String`
+    /// can be deserialized from the text of the root element.
+    fn root_tag(&mut self) -> Result<()> {
+        if self.init {
+            return Ok(());
+        }
+        loop {
+            let event = self.next_tag()?;
+            if event.type_ != EventType::TagClose {
+                self.tags.push(event);
+                return Ok(());
+            }
+        }
+    }
+
     fn next_tag(&mut self) -> Result<Event> {
         let tag = self.next_tag_cache.take();
         if let Some(tag) = tag {
@@ -139,6 +203,10 @@ impl<R: Read> Deserializer<R> {
     }
 
     fn close_tag(&mut self) -> Result<()> {
+        if self.top_tag()?.type_ == EventType::EmptyTag {
+            self.tags.pop();
+            return Ok(());
+        }
         let next_tag = self.next_tag()?;
         let top_tag = self.top_tag()?;
         if !next_tag.is_tag() {
@@ -230,6 +298,7 @@ impl<'de, 'a, R: Read> serde::de::Deserializer<'de> for &'a mut Deserializer<R>
     where
         V: serde::de::Visitor<'de>,
     {
+        self.root_tag()?;
         let tag = self.top_tag()?;
         let result = visitor.visit_str(&tag.content());
         if result.is_ok() {
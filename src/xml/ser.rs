@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::io::{BufWriter, Write};
 
 use serde::Serialize;
@@ -115,6 +116,16 @@ impl<'ser, W: Write> serde::ser::Serializer for &'ser mut Serializer<W> {
 
     #[inline]
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        // escape the text content, `"` and `'` are only special in attributes.
+        let v: Cow<str> = if v.contains(['&', '<', '>', '\r']) {
+            v.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('\r', "&#13;")
+                .into()
+        } else {
+            v.into()
+        };
         self.serialize_bytes(v.as_bytes())
     }
 
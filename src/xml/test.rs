@@ -5,9 +5,10 @@ mod test {
 
     use crate::{
         datatype::{
-            CompleteMultipartUploadResult, CopyPartResult, InitiateMultipartUploadResult,
-            LegalHold, ListAllMyBucketsResult, ListBucketResult, ListMultipartUploadsResult,
-            ListPartsResult, ObjectLockConfiguration, Retention, Tagging, VersioningConfiguration,
+            CompleteMultipartUploadResult, CopyPartResult, Delete, DeleteResult,
+            InitiateMultipartUploadResult, LegalHold, ListAllMyBucketsResult, ListBucketResult,
+            ListMultipartUploadsResult, ListPartsResult, ListVersionsResult, ObjectIdentifier,
+            ObjectLockConfiguration, Retention, Tagging, VersioningConfiguration,
         },
         xml::de::from_str,
     };
@@ -281,6 +282,124 @@ mod test {
         </VersioningConfiguration>"#
     );
 
+    #[test]
+    fn test_list_versions_result() {
+        let txt = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ListVersionsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Name>bucket</Name>
+            <Prefix>my</Prefix>
+            <KeyMarker></KeyMarker>
+            <VersionIdMarker></VersionIdMarker>
+            <NextKeyMarker>my-second-image.jpg</NextKeyMarker>
+            <NextVersionIdMarker>03jpff543dhffds434rfdsFDN943fdsFkdmqnh892</NextVersionIdMarker>
+            <MaxKeys>5</MaxKeys>
+            <IsTruncated>true</IsTruncated>
+            <Version>
+                <Key>my-image.jpg</Key>
+                <VersionId>3/L4kqtJl40Nr8X8gdRQBpUMLUo</VersionId>
+                <IsLatest>true</IsLatest>
+                <LastModified>2009-10-12T17:50:30.000Z</LastModified>
+                <ETag>"fba9dede5f27731c9771645a39863328"</ETag>
+                <Size>434234</Size>
+                <StorageClass>STANDARD</StorageClass>
+                <Owner>
+                    <ID>75aa57f09aa0c8caeab4f8c24e99d10f8e7faeebf76c078efc7c6caea54ba06a</ID>
+                    <DisplayName>mtd@amazon.com</DisplayName>
+                </Owner>
+            </Version>
+            <DeleteMarker>
+                <Key>my-second-image.jpg</Key>
+                <VersionId>03jpff543dhffds434rfdsFDN943fdsFkdmqnh892</VersionId>
+                <IsLatest>true</IsLatest>
+                <LastModified>2009-11-12T17:50:30.000Z</LastModified>
+                <Owner>
+                    <ID>75aa57f09aa0c8caeab4f8c24e99d10f8e7faeebf76c078efc7c6caea54ba06a</ID>
+                    <DisplayName>mtd@amazon.com</DisplayName>
+                </Owner>
+            </DeleteMarker>
+            <Version>
+                <Key>my-second-image.jpg</Key>
+                <VersionId>QUpfdndhfd8438MNFDN93jdnJFkdmqnh893</VersionId>
+                <IsLatest>false</IsLatest>
+                <LastModified>2009-10-10T17:50:30.000Z</LastModified>
+                <ETag>"9b2cf535f27731c974343645a3985328"</ETag>
+                <Size>166434</Size>
+                <StorageClass>STANDARD</StorageClass>
+            </Version>
+        </ListVersionsResult>"#;
+        let res = from_str::<ListVersionsResult>(txt).unwrap();
+        assert_eq!(res.versions.len(), 2);
+        assert_eq!(res.delete_markers.len(), 1);
+        assert!(res.is_truncated);
+        assert_eq!(res.next_key_marker, "my-second-image.jpg");
+        assert_eq!(
+            res.versions[1].version_id,
+            "QUpfdndhfd8438MNFDN93jdnJFkdmqnh893"
+        );
+        assert!(res.versions[1].owner.is_none());
+        assert_eq!(res.delete_markers[0].key, "my-second-image.jpg");
+
+        let xml = crate::xml::ser::to_string(&res).unwrap();
+        let round_trip = from_str::<ListVersionsResult>(&xml).unwrap();
+        assert_eq!(round_trip.versions.len(), 2);
+        assert_eq!(round_trip.delete_markers.len(), 1);
+        assert_eq!(round_trip.next_key_marker, res.next_key_marker);
+        assert_eq!(crate::xml::ser::to_string(&round_trip).unwrap(), xml);
+    }
+
+    #[test]
+    fn test_delete() {
+        let delete = Delete {
+            quiet: true,
+            objects: vec![
+                ObjectIdentifier {
+                    key: "a&b<c>.txt".to_string(),
+                    version_id: None,
+                },
+                ObjectIdentifier {
+                    key: "d.txt".to_string(),
+                    version_id: Some("3/L4kqtJl40Nr8X8gdRQBpUMLUo".to_string()),
+                },
+            ],
+        };
+        let xml = crate::xml::ser::to_string(&delete).unwrap();
+        assert_eq!(
+            xml,
+            "<Delete><Quiet>true</Quiet><Object><Key>a&amp;b&lt;c&gt;.txt</Key></Object><Object><Key>d.txt</Key><VersionId>3/L4kqtJl40Nr8X8gdRQBpUMLUo</VersionId></Object></Delete>"
+        );
+        let res = from_str::<Delete>(&xml).unwrap();
+        assert_eq!(res.objects[0].key, "a&b<c>.txt");
+    }
+
+    #[test]
+    fn test_delete_result() {
+        let txt = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <DeleteResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+            <Deleted>
+                <Key>sample1.txt</Key>
+            </Deleted>
+            <Error>
+                <Key>sample2.txt</Key>
+                <Code>AccessDenied</Code>
+                <Message>Access Denied</Message>
+            </Error>
+            <Deleted>
+                <Key>sample3.txt</Key>
+                <VersionId>3/L4kqtJl40Nr8X8gdRQBpUMLUo</VersionId>
+                <DeleteMarker>true</DeleteMarker>
+                <DeleteMarkerVersionId>NeQt5xeFTfgPJD8B4CGWnkSLtluMr11s</DeleteMarkerVersionId>
+            </Deleted>
+        </DeleteResult>"#;
+        let res = from_str::<DeleteResult>(txt).unwrap();
+        assert_eq!(res.deleted.len(), 2);
+        assert_eq!(res.errors.len(), 1);
+        assert_eq!(res.errors[0].code, "AccessDenied");
+        assert_eq!(res.deleted[1].delete_marker, Some(true));
+
+        let res = from_str::<DeleteResult>("<DeleteResult></DeleteResult>").unwrap();
+        assert!(res.deleted.is_empty() && res.errors.is_empty());
+    }
+
     #[test]
     fn test_struct() {
         let j = r#"<Test><nme><Abc><first>323</first></Abc></name></Test>0"#;
@@ -338,4 +457,34 @@ mod test {
         //     .as_nanos();
         // println!("{s}");
     }
+
+    #[test]
+    fn test_escape() {
+        #[derive(Debug, PartialEq, serde::Serialize, Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Object {
+            key: String,
+            version_id: Option<String>,
+        }
+
+        let object = Object {
+            key: "a&b<c>\"d'\r.txt".to_string(),
+            version_id: Some("&amp;".to_string()),
+        };
+        let xml = crate::xml::ser::to_string(&object).unwrap();
+        assert_eq!(
+            xml,
+            "<Object><Key>a&amp;b&lt;c&gt;\"d'&#13;.txt</Key><VersionId>&amp;amp;</VersionId></Object>"
+        );
+        assert_eq!(from_str::<Object>(&xml).unwrap(), object);
+
+        let xml =
+            "<Object><Key>&quot;&apos;&#x41;&#66;&#xD;&amp;lt;&unknown;&#xZZ;a&b</Key></Object>";
+        let object = from_str::<Object>(xml).unwrap();
+        assert_eq!(object.key, "\"'AB\r&lt;&unknown;&#xZZ;a&b");
+        assert!(object.version_id.is_none());
+
+        let object = from_str::<Object>("<Object><Key>plain.txt</Key></Object>").unwrap();
+        assert_eq!(object.key, "plain.txt");
+    }
 }
@@ -6,9 +6,9 @@ export MINIO_SECRET_KEY=minio-secret-key-test
 
 export virtual_hosted_style=false
 export multi_chunked=false
-cargo test --features "fs-tokio"
+cargo test --features "fs-tokio io-tokio"
 
 
 export virtual_hosted_style=true
 export multi_chunked=true
-cargo test --features "fs-tokio"
\ No newline at end of file
+cargo test --features "fs-tokio io-tokio"
\ No newline at end of file
@@ -1,9 +1,11 @@
 mod common;
 
 use common::get_test_minio;
-use minio_rsc::client::{ObjectLockConfig, PresignedArgs, Tags};
+use hyper::Method;
+use minio_rsc::client::{ObjectLockConfig, PostPolicy, PresignedArgs, Tags};
 use minio_rsc::datatype::VersioningStatus;
 use minio_rsc::error::Result;
+use minio_rsc::time::UtcTime;
 use tokio;
 
 #[tokio::main]
@@ -54,6 +56,9 @@ async fn test_bucket() -> Result<()> {
     assert!(minio.delete_object_lock_config(bucket2).await.is_ok());
     println!("get {:?}", minio.get_object_lock_config(bucket2).await);
 
+    println!("====== begin test get_bucket_location");
+    assert_eq!(minio.get_bucket_location(bucket1).await?, minio.region());
+
     println!("====== begin clear test bucket");
     assert!(minio.bucket_exists(bucket1).await?);
     assert!(minio.remove_bucket(bucket1).await.is_ok());
@@ -76,5 +81,245 @@ async fn test_presigned() -> Result<()> {
         .presigned_put_object(PresignedArgs::new("bucket", "file.txt").expires(24 * 3600))
         .await?;
     println!("put url {}", url);
+    let url = minio
+        .presigned_url(Method::DELETE, PresignedArgs::new("bucket", "file.txt"))
+        .await?;
+    assert!(url.contains("X-Amz-SignedHeaders=host&"));
+    let url = minio
+        .presigned_url(
+            Method::PUT,
+            PresignedArgs::new("bucket", "file.txt")
+                .query("partNumber", "1")
+                .query("uploadId", "upload_id")
+                .signed_header("content-type", "text/plain")?,
+        )
+        .await?;
+    assert!(url.contains("partNumber=1"));
+    assert!(url.contains("X-Amz-SignedHeaders=content-type%3Bhost&"));
+    // invalid header values are rejected instead of dropped.
+    let args =
+        PresignedArgs::new("bucket", "file.txt").signed_header("content-type", "text\nplain");
+    assert!(args.is_err());
+
+    let expiration = UtcTime::new(chrono::Utc::now() + chrono::Duration::hours(1));
+    let policy = PostPolicy::new("bucket", expiration)
+        .key_starts_with("uploads/")
+        .content_length_range(1, 1024);
+    let form_data = minio.presigned_post_policy(policy).await?;
+    assert_eq!(form_data["key"], "uploads/");
+    assert_eq!(form_data["x-amz-algorithm"], "AWS4-HMAC-SHA256");
+    assert!(form_data.contains_key("policy"));
+    assert!(form_data.contains_key("x-amz-signature"));
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_bucket_policy() -> Result<()> {
+    use minio_rsc::datatype::{Effect, PolicyDocument};
+
+    let minio = get_test_minio();
+    let bucket = "test-bucket-policy";
+    common::create_bucket_if_not_exist(&minio, bucket).await?;
+
+    assert!(minio.get_bucket_policy(bucket).await?.is_none());
+    let policy = PolicyDocument::read_only(bucket, "public/");
+    minio.set_bucket_policy(bucket, policy).await?;
+    let policy = minio.get_bucket_policy(bucket).await?.unwrap();
+    assert!(policy.statements.iter().all(|s| s.effect == Effect::Allow));
+    assert!(policy
+        .statements
+        .iter()
+        .any(|s| s.action.contains(&"s3:GetObject".to_string())));
+
+    let bucket = minio.bucket(bucket);
+    bucket.delete_bucket_policy().await?;
+    assert!(bucket.get_bucket_policy().await?.is_none());
+    minio.remove_bucket(bucket).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_bucket_lifecycle() -> Result<()> {
+    use minio_rsc::datatype::{Expiration, Filter, LifecycleConfiguration, LifecycleRule};
+
+    let minio = get_test_minio();
+    let bucket = "test-bucket-lifecycle";
+    common::create_bucket_if_not_exist(&minio, bucket).await?;
+
+    assert!(minio.get_bucket_lifecycle(bucket).await?.is_none());
+    let config = LifecycleConfiguration::new(vec![
+        LifecycleRule::new("expire-logs")
+            .filter(Filter::prefix("logs/"))
+            .expiration(Expiration::days(30)),
+        LifecycleRule::new("abort-uploads").abort_incomplete_multipart_upload(7),
+    ]);
+    minio.set_bucket_lifecycle(bucket, config).await?;
+    let config = minio.get_bucket_lifecycle(bucket).await?.unwrap();
+    assert_eq!(config.rules.len(), 2);
+    let rule = config
+        .rules
+        .iter()
+        .find(|r| r.id.as_deref() == Some("expire-logs"))
+        .unwrap();
+    assert_eq!(rule.filter.prefix.as_deref(), Some("logs/"));
+    assert_eq!(rule.expiration.as_ref().unwrap().days, Some(30));
+
+    let bucket = minio.bucket(bucket);
+    bucket.delete_bucket_lifecycle().await?;
+    assert!(bucket.get_bucket_lifecycle().await?.is_none());
+    minio.remove_bucket(bucket).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_bucket_notification() -> Result<()> {
+    let minio = get_test_minio();
+    let bucket = "test-bucket-notification";
+    common::create_bucket_if_not_exist(&minio, bucket).await?;
+
+    // no notification target is configured on the test server, so only the empty configuration is set.
+    let config = minio.get_bucket_notification(bucket).await?;
+    assert!(config.is_empty());
+    minio.set_bucket_notification(bucket, config).await?;
+
+    let bucket = minio.bucket(bucket);
+    bucket.delete_bucket_notification().await?;
+    assert!(bucket.get_bucket_notification().await?.is_empty());
+    minio.remove_bucket(bucket).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_listen_bucket_notification() -> Result<()> {
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    let minio = get_test_minio();
+    let bucket = "test-listen-notification";
+    common::create_bucket_if_not_exist(&minio, bucket).await?;
+
+    let events = vec!["s3:ObjectCreated:*".to_string()];
+    let mut stream = minio.listen_bucket_notification(bucket, "listen/", ".txt", events);
+    let client = minio.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        client
+            .put_object(bucket, "listen/a.json", "{}".into())
+            .await?;
+        client
+            .put_object(bucket, "listen/a.txt", "hello".into())
+            .await
+    });
+    let record = tokio::time::timeout(Duration::from_secs(10), stream.next())
+        .await
+        .expect("no event received")
+        .unwrap()?;
+    assert!(record.event_name.starts_with("s3:ObjectCreated:"));
+    assert_eq!(record.s3.bucket.name, bucket);
+    assert_eq!(record.s3.object.size, Some(5));
+    drop(stream);
+
+    minio.remove_object(bucket, "listen/a.json").await?;
+    minio.remove_object(bucket, "listen/a.txt").await?;
+    minio.remove_bucket(bucket).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_bucket_encryption() -> Result<()> {
+    use minio_rsc::datatype::ServerSideEncryptionConfiguration;
+    use minio_rsc::error::Error;
+    use minio_rsc::sse::SseS3;
+
+    let minio = get_test_minio();
+    let bucket = "test-bucket-encryption";
+    common::create_bucket_if_not_exist(&minio, bucket).await?;
+
+    match minio.get_bucket_encryption(bucket).await {
+        Ok(result) => assert!(result.is_none()),
+        // the test server may run without KMS.
+        Err(Error::S3Error(e)) if e.code == "NotImplemented" => {
+            minio.remove_bucket(bucket).await?;
+            return Ok(());
+        }
+        Err(e) => return Err(e),
+    }
+    let config = ServerSideEncryptionConfiguration::from(&SseS3::new());
+    match minio.set_bucket_encryption(bucket, config.clone()).await {
+        Ok(_) => {
+            let result = minio.get_bucket_encryption(bucket).await?;
+            assert_eq!(result, Some(config));
+        }
+        // the test server may run without KMS.
+        Err(Error::S3Error(e)) if e.code == "NotImplemented" => {}
+        Err(e) => return Err(e),
+    }
+
+    let bucket = minio.bucket(bucket);
+    bucket.delete_bucket_encryption().await?;
+    assert!(bucket.get_bucket_encryption().await?.is_none());
+    minio.remove_bucket(bucket).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_bucket_replication() -> Result<()> {
+    let minio = get_test_minio();
+    let bucket = "test-bucket-replication";
+    common::create_bucket_if_not_exist(&minio, bucket).await?;
+
+    // setting rules requires a remote target on the server, only the empty state is checked.
+    assert!(minio.get_bucket_replication(bucket).await?.is_none());
+    let bucket = minio.bucket(bucket);
+    assert!(bucket.get_bucket_replication().await?.is_none());
+    minio.remove_bucket(bucket).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_bucket_cors() -> Result<()> {
+    use minio_rsc::datatype::{CorsConfiguration, CorsRule};
+    use minio_rsc::error::Error;
+
+    let minio = get_test_minio();
+    let bucket = "test-bucket-cors";
+    common::create_bucket_if_not_exist(&minio, bucket).await?;
+
+    match minio.get_bucket_cors(bucket).await {
+        Ok(result) => assert!(result.is_none()),
+        // older MinIO servers don't support bucket CORS.
+        Err(Error::S3Error(e)) if e.code == "NotImplemented" => {
+            minio.remove_bucket(bucket).await?;
+            return Ok(());
+        }
+        Err(e) => return Err(e),
+    }
+    let config = CorsConfiguration::new(vec![CorsRule::new(
+        vec!["https://example.com".to_string()],
+        vec!["PUT".to_string(), "GET".to_string()],
+    )
+    .expose_headers(vec!["ETag".to_string()])
+    .max_age_seconds(3000)]);
+    match minio.set_bucket_cors(bucket, config.clone()).await {
+        Ok(_) => {
+            let result = minio.get_bucket_cors(bucket).await?;
+            assert_eq!(result, Some(config));
+            let bucket = minio.bucket(bucket);
+            bucket.delete_bucket_cors().await?;
+            assert!(bucket.get_bucket_cors().await?.is_none());
+        }
+        // older MinIO servers don't support bucket CORS.
+        Err(Error::S3Error(e)) if e.code == "NotImplemented" => {}
+        Err(e) => return Err(e),
+    }
+
+    minio.remove_bucket(bucket).await?;
     Ok(())
 }
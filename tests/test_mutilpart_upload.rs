@@ -1,7 +1,7 @@
 mod common;
 
 use common::get_test_minio;
-use minio_rsc::error::Result;
+use minio_rsc::error::{Error, Result};
 use tokio;
 
 pub const MIN_PART_SIZE: usize = 5 * 1024 * 1024; // 5MiB
@@ -37,3 +37,176 @@ async fn test_mutilpart_upload() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::main]
+#[test]
+async fn test_parallel_put_object_stream() -> Result<()> {
+    use futures::stream;
+    use minio_rsc::client::UploadConfig;
+    use minio_rsc::provider::StaticProvider;
+    use minio_rsc::Minio;
+
+    dotenv::dotenv().ok();
+    let host = std::env::var("MINIO_HOST").unwrap_or("localhost:9022".to_owned());
+    let minio = Minio::builder()
+        .endpoint(host)
+        .provider(StaticProvider::from_env().unwrap())
+        .secure(false)
+        .upload_config(
+            UploadConfig::default()
+                .part_size(MIN_PART_SIZE)
+                .max_concurrency(3),
+        )
+        .build()
+        .unwrap();
+    let bucket = "test-parallel-upload";
+    let object_key = "test.obj";
+    common::create_bucket_if_not_exist(&minio, bucket).await?;
+
+    // 4 parts, the last one is smaller.
+    let len = MIN_PART_SIZE * 3 + 1024;
+    let chunks: Vec<Result<bytes::Bytes>> = (0..len / 1024)
+        .map(|i| Ok(bytes::Bytes::from(vec![(i % 256) as u8; 1024])))
+        .collect();
+    minio
+        .put_object_stream(bucket, object_key, Box::pin(stream::iter(chunks)), None)
+        .await?;
+    let stat = minio.stat_object(bucket, object_key).await?.unwrap();
+    assert_eq!(stat.size(), len);
+    assert!(stat.etag().trim_matches('"').ends_with("-4"));
+
+    let data = minio.get_object(bucket, object_key).await?.bytes().await?;
+    for (i, chunk) in data.chunks(1024).enumerate() {
+        assert!(chunk.iter().all(|b| *b == (i % 256) as u8));
+    }
+
+    assert!(minio.remove_object(bucket, object_key).await.is_ok());
+    assert!(minio.remove_bucket(bucket).await.is_ok());
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+#[cfg(feature = "fs-tokio")]
+async fn test_fput_object_resumable() -> Result<()> {
+    use minio_rsc::client::{
+        CheckpointFuture, CheckpointStore, MemoryCheckpointStore, UploadCheckpoint,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Fails after saving the checkpoint `limit` times, like a crashed upload.
+    struct FailingStore {
+        inner: MemoryCheckpointStore,
+        saves: AtomicUsize,
+        limit: usize,
+    }
+
+    impl CheckpointStore for FailingStore {
+        fn load<'a>(&'a self, id: &'a str) -> CheckpointFuture<'a, Option<UploadCheckpoint>> {
+            self.inner.load(id)
+        }
+
+        fn save<'a>(
+            &'a self,
+            id: &'a str,
+            checkpoint: &'a UploadCheckpoint,
+        ) -> CheckpointFuture<'a, ()> {
+            if self.saves.fetch_add(1, Ordering::SeqCst) >= self.limit {
+                return Box::pin(async { Err(std::io::ErrorKind::Other.into()) });
+            }
+            self.inner.save(id, checkpoint)
+        }
+
+        fn remove<'a>(&'a self, id: &'a str) -> CheckpointFuture<'a, ()> {
+            self.inner.remove(id)
+        }
+    }
+
+    dotenv::dotenv().ok();
+    let minio = get_test_minio();
+    let bucket = "test-resumable-upload";
+    let object_key = "test.obj";
+    common::create_bucket_if_not_exist(&minio, bucket).await?;
+
+    let path = std::env::temp_dir().join("minio-rsc-test-resumable.obj");
+    let len = 16 * 1024 * 1024 * 2 + 1024;
+    let data: Vec<u8> = (0..len).map(|i| (i / 1024 % 256) as u8).collect();
+    std::fs::write(&path, &data).unwrap();
+
+    let store = MemoryCheckpointStore::new();
+    let failing = FailingStore {
+        inner: store.clone(),
+        saves: AtomicUsize::new(0),
+        limit: 2,
+    };
+    assert!(minio
+        .fput_object_resumable(bucket, object_key, &path, &failing)
+        .await
+        .is_err());
+    assert_eq!(store.len(), 1);
+
+    minio
+        .fput_object_resumable(bucket, object_key, &path, &store)
+        .await?;
+    assert!(store.is_empty());
+    let stat = minio.stat_object(bucket, object_key).await?.unwrap();
+    assert_eq!(stat.size(), len);
+    let content = minio.get_object(bucket, object_key).await?.bytes().await?;
+    assert!(content == data);
+
+    std::fs::remove_file(path).ok();
+    assert!(minio.remove_object(bucket, object_key).await.is_ok());
+    assert!(minio.remove_bucket(bucket).await.is_ok());
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_compose_object() -> Result<()> {
+    use minio_rsc::client::CopySource;
+
+    dotenv::dotenv().ok();
+    let minio = get_test_minio();
+    let bucket = "test-compose-object";
+    common::create_bucket_if_not_exist(&minio, bucket).await?;
+
+    let part1 = bytes::Bytes::from(vec![b'a'; MIN_PART_SIZE]);
+    let part2 = b"hello minio".to_vec();
+    minio.put_object(bucket, "part1", part1.clone()).await?;
+    minio.put_object(bucket, "part2", part2.into()).await?;
+
+    let sources = vec![
+        CopySource::new(bucket, "part1"),
+        CopySource::new(bucket, "part2").range(6, 5),
+    ];
+    minio.compose_object(bucket, "composed", sources).await?;
+    let data = minio.get_object(bucket, "composed").await?.bytes().await?;
+    assert_eq!(data.len(), MIN_PART_SIZE + 5);
+    assert!(data[..MIN_PART_SIZE] == part1[..]);
+    assert_eq!(&data[MIN_PART_SIZE..], b"minio");
+
+    // only the last source can be smaller than 5MiB.
+    let sources = vec![
+        CopySource::new(bucket, "part2"),
+        CopySource::new(bucket, "part1"),
+    ];
+    let res = minio.compose_object(bucket, "composed", sources).await;
+    assert!(res.is_err());
+
+    // empty sources are rejected.
+    minio
+        .put_object(bucket, "empty", bytes::Bytes::new())
+        .await?;
+    let sources = vec![
+        CopySource::new(bucket, "part1"),
+        CopySource::new(bucket, "empty"),
+    ];
+    let res = minio.compose_object(bucket, "composed", sources).await;
+    assert!(matches!(res, Err(Error::ValueError(_))));
+
+    for key in ["part1", "part2", "empty", "composed"] {
+        minio.remove_object(bucket, key).await?;
+    }
+    minio.remove_bucket(bucket).await?;
+    Ok(())
+}
@@ -4,10 +4,16 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 use common::{create_bucket_if_not_exist, get_test_minio};
-use futures_util::{stream, StreamExt};
+use futures_util::{stream, StreamExt, TryStreamExt};
 use minio_rsc::client::CopySource;
 use minio_rsc::client::KeyArgs;
+use minio_rsc::client::ListObjectVersionsArgs;
+use minio_rsc::client::ListObjectVersionsItem;
+use minio_rsc::client::ListObjectsArgs;
+use minio_rsc::client::ListObjectsItem;
 use minio_rsc::client::ObjectLockConfig;
+use minio_rsc::client::RemoveObjectsArgs;
+use minio_rsc::client::RemoveObjectsItem;
 use minio_rsc::client::Tags;
 use minio_rsc::datatype::CompressionType;
 use minio_rsc::datatype::CsvInput;
@@ -15,6 +21,8 @@ use minio_rsc::datatype::InputSerialization;
 use minio_rsc::datatype::JsonOutput;
 use minio_rsc::datatype::ObjectLockConfiguration;
 use minio_rsc::datatype::SelectRequest;
+use minio_rsc::datatype::VersioningConfiguration;
+use minio_rsc::datatype::VersioningStatus;
 use minio_rsc::error::Result;
 use tokio;
 
@@ -33,6 +41,20 @@ async fn test_base_operate() -> Result<()> {
     bucket.put_object(key, txt.into()).await?;
 
     assert_eq!(bucket.get_object(object).await?.text().await?, txt);
+    let range = KeyArgs::new(object).offset(6).length(5);
+    let output = bucket.get_object(range).await?;
+    assert_eq!(output.content_length(), 5);
+    assert_eq!(output.content_type(), "text/plain");
+    assert_eq!(output.content_range(), Some("bytes 6-10/11"));
+    assert_eq!(output.text().await?, "minio");
+    #[cfg(feature = "io-tokio")]
+    {
+        use tokio::io::AsyncReadExt;
+        let mut reader = bucket.get_object(object).await?.into_async_read();
+        let mut text = String::new();
+        reader.read_to_string(&mut text).await?;
+        assert_eq!(text, txt);
+    }
 
     let stat = bucket.stat_object(object).await?.unwrap();
     assert_eq!(stat.object_name(), object);
@@ -113,6 +135,129 @@ async fn test_file_operate() -> Result<()> {
     Ok(())
 }
 
+#[tokio::main]
+#[test]
+#[cfg(feature = "fs-tokio")]
+async fn test_fget_object_ranged() -> Result<()> {
+    use minio_rsc::client::DownloadConfig;
+    use minio_rsc::provider::StaticProvider;
+    use minio_rsc::Minio;
+
+    dotenv::dotenv().ok();
+    let host = std::env::var("MINIO_HOST").unwrap_or("localhost:9022".to_owned());
+    let minio = Minio::builder()
+        .endpoint(host)
+        .provider(StaticProvider::from_env().unwrap())
+        .secure(false)
+        .download_config(DownloadConfig::default().part_size(1024 * 1024))
+        .build()
+        .unwrap();
+    let bucket = "test-fget-ranged";
+    let object_name = "test.obj";
+    create_bucket_if_not_exist(&minio, bucket).await?;
+
+    let len = 3 * 1024 * 1024 + 512;
+    let data: Vec<u8> = (0..len).map(|i| (i / 1000 % 256) as u8).collect();
+    minio
+        .put_object(bucket, object_name, data.clone().into())
+        .await?;
+    let path = std::env::temp_dir().join("minio-rsc-test-fget-ranged.obj");
+    minio.fget_object(bucket, object_name, &path).await?;
+    assert!(std::fs::read(&path).unwrap() == data);
+
+    // resume with the last range missing.
+    let stat = minio.stat_object(bucket, object_name).await?.unwrap();
+    let mut part = data.clone();
+    part[3 * 1024 * 1024..].fill(0);
+    std::fs::write(path.with_extension("obj.part"), part).unwrap();
+    let state = format!(
+        r#"{{"etag":"{}","size":{},"part_size":{},"done":[0,1,2]}}"#,
+        stat.etag(),
+        len,
+        1024 * 1024
+    );
+    std::fs::write(path.with_extension("obj.part.json"), state).unwrap();
+    minio.fget_object(bucket, object_name, &path).await?;
+    assert!(std::fs::read(&path).unwrap() == data);
+    assert!(!path.with_extension("obj.part").exists());
+    assert!(!path.with_extension("obj.part.json").exists());
+
+    std::fs::remove_file(path).ok();
+    minio.remove_object(bucket, object_name).await?;
+    minio.remove_bucket(bucket).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+#[cfg(feature = "fs-tokio")]
+async fn test_fget_object_sse() -> Result<()> {
+    use minio_rsc::error::Error;
+    use minio_rsc::sse::{Sse, SseS3};
+
+    let minio = get_test_minio();
+    let bucket = "test-fget-sse";
+    let object_name = "test.obj";
+    create_bucket_if_not_exist(&minio, bucket).await?;
+
+    let data = "encrypted data".repeat(1024);
+    let key = KeyArgs::new(object_name).extra_headers(Some(SseS3::new().headers()));
+    match minio.put_object(bucket, key, data.clone().into()).await {
+        Ok(_) => {
+            let stat = minio.stat_object(bucket, object_name).await?.unwrap();
+            assert_eq!(stat.server_side_encryption(), Some("AES256"));
+            // the ETag of an encrypted object is not the MD5 of its data.
+            let path = std::env::temp_dir().join("minio-rsc-test-fget-sse.obj");
+            minio.fget_object(bucket, object_name, &path).await?;
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), data);
+            std::fs::remove_file(path).ok();
+            minio.remove_object(bucket, object_name).await?;
+        }
+        // the test server may run without KMS.
+        Err(Error::S3Error(e)) if e.code == "NotImplemented" => {}
+        Err(e) => return Err(e),
+    }
+    minio.remove_bucket(bucket).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_conditional_operate() -> Result<()> {
+    use minio_rsc::error::Error;
+
+    let minio = get_test_minio();
+    let bucket = "test-conditional-operate";
+    let object = "test.txt";
+    create_bucket_if_not_exist(&minio, bucket).await?;
+
+    let key = KeyArgs::new(object).create_only();
+    minio.put_object(bucket, key.clone(), "v1".into()).await?;
+    let res = minio.put_object(bucket, key, "v2".into()).await;
+    assert!(matches!(res, Err(Error::PreconditionFailed)));
+
+    let stat = minio.stat_object(bucket, object).await?.unwrap();
+    let etag = Some(stat.etag().to_string());
+    let key = KeyArgs::new(object).not_match_etag(etag.clone());
+    let res = minio.get_object(bucket, key.clone()).await;
+    assert!(matches!(res, Err(Error::NotModified)));
+    let res = minio.stat_object(bucket, key).await;
+    assert!(matches!(res, Err(Error::NotModified)));
+
+    let key = KeyArgs::new(object).match_etag(etag.clone());
+    minio.put_object(bucket, key.clone(), "v2".into()).await?;
+    let res = minio.put_object(bucket, key.clone(), "v3".into()).await;
+    assert!(matches!(res, Err(Error::PreconditionFailed)));
+    let res = minio.get_object(bucket, key).await;
+    assert!(matches!(res, Err(Error::PreconditionFailed)));
+    let output = minio.get_object(bucket, object).await?;
+    assert_eq!(output.text().await?, "v2");
+
+    minio.remove_object(bucket, object).await?;
+    minio.remove_bucket(bucket).await?;
+    Ok(())
+}
+
 #[tokio::main]
 #[test]
 async fn test_put_stream() -> Result<()> {
@@ -191,3 +336,107 @@ async fn test_select_object() -> Result<()> {
     minio.remove_bucket(bucket).await?;
     Ok(())
 }
+
+#[tokio::main]
+#[test]
+async fn test_list_objects_stream() -> Result<()> {
+    let minio = get_test_minio();
+
+    let bucket_name = "test-list-objects-stream";
+    create_bucket_if_not_exist(&minio, bucket_name).await?;
+    let bucket = minio.bucket(bucket_name);
+    for i in 0..5 {
+        bucket
+            .put_object(format!("dir/{i}.txt"), "hello".into())
+            .await?;
+    }
+    bucket.put_object("root.txt", "hello".into()).await?;
+
+    // small page size to exercise the continuation token
+    let args = ListObjectsArgs::default().max_keys(2).recursive(true);
+    let items: Vec<ListObjectsItem> = bucket.list_objects_stream(args).try_collect().await?;
+    assert_eq!(items.len(), 6);
+
+    let args = ListObjectsArgs::default().max_keys(2).recursive(false);
+    let items: Vec<ListObjectsItem> = bucket.list_objects_stream(args).try_collect().await?;
+    let prefixes = items
+        .iter()
+        .filter(|f| matches!(f, ListObjectsItem::CommonPrefix(_)))
+        .count();
+    assert_eq!(prefixes, 1);
+    assert_eq!(items.len(), 2);
+
+    for i in 0..5 {
+        bucket.remove_object(format!("dir/{i}.txt")).await?;
+    }
+    bucket.remove_object("root.txt").await?;
+    minio.remove_bucket(bucket_name).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_list_object_versions() -> Result<()> {
+    let minio = get_test_minio();
+
+    let bucket_name = "test-list-object-versions";
+    create_bucket_if_not_exist(&minio, bucket_name).await?;
+    let bucket = minio.bucket(bucket_name);
+    let versioning = VersioningConfiguration {
+        mfa_delete: None,
+        status: Some(VersioningStatus::Enabled),
+    };
+    minio.set_bucket_versioning(bucket_name, versioning).await?;
+
+    let key = "test.txt";
+    bucket.put_object(key, "hello".into()).await?;
+    bucket.put_object(key, "hello minio".into()).await?;
+    bucket.remove_object(key).await?;
+
+    let args = ListObjectVersionsArgs::default().max_keys(1);
+    let items: Vec<ListObjectVersionsItem> = bucket
+        .list_object_versions_stream(args)
+        .try_collect()
+        .await?;
+    assert_eq!(items.len(), 3);
+
+    for item in items {
+        let version_id = match item {
+            ListObjectVersionsItem::Version(v) => v.version_id,
+            ListObjectVersionsItem::DeleteMarker(m) => m.version_id,
+            ListObjectVersionsItem::CommonPrefix(_) => continue,
+        };
+        let key = KeyArgs::new(key).version_id(Some(version_id));
+        bucket.remove_object(key).await?;
+    }
+    minio.remove_bucket(bucket_name).await?;
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_remove_objects() -> Result<()> {
+    let minio = get_test_minio();
+
+    let bucket_name = "test-remove-objects";
+    create_bucket_if_not_exist(&minio, bucket_name).await?;
+    let bucket = minio.bucket(bucket_name);
+    let keys: Vec<String> = (0..10).map(|i| format!("dir/{i}.txt")).collect();
+    for key in &keys {
+        bucket.put_object(key.as_str(), "hello".into()).await?;
+    }
+
+    let items: Vec<RemoveObjectsItem> = bucket
+        .remove_objects(stream::iter(keys), RemoveObjectsArgs::default())
+        .try_collect()
+        .await?;
+    assert_eq!(items.len(), 10);
+    assert!(items
+        .iter()
+        .all(|f| matches!(f, RemoveObjectsItem::Deleted(_))));
+
+    let args = ListObjectsArgs::default();
+    assert!(bucket.list_objects(args).await?.contents.is_empty());
+    minio.remove_bucket(bucket_name).await?;
+    Ok(())
+}
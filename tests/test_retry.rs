@@ -0,0 +1,162 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use hyper::Method;
+use minio_rsc::client::RetryPolicy;
+use minio_rsc::error::Result;
+use minio_rsc::provider::StaticProvider;
+use minio_rsc::Minio;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Start a stub S3 server responding `responses` of (status, body) in turn,
+/// the last one is repeated. Returns its host and the count of received requests.
+async fn start_s3_stub(responses: Vec<(&'static str, &'static str)>) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let count = Arc::new(AtomicUsize::new(0));
+    let requests = count.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = match listener.accept().await {
+                Ok(s) => s,
+                Err(_) => return,
+            };
+            let n = requests.fetch_add(1, Ordering::SeqCst);
+            let mut buf = vec![0u8; 4096];
+            socket.read(&mut buf).await.ok();
+            let (status, body) = responses[n.min(responses.len() - 1)];
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.ok();
+        }
+    });
+    (addr.to_string(), count)
+}
+
+fn stub_minio(host: String, retry: RetryPolicy) -> Minio {
+    Minio::builder()
+        .endpoint(host)
+        .secure(false)
+        .region("us-east-1")
+        .provider(StaticProvider::new("access-key", "secret-key", None))
+        .retry_policy(retry.base_delay(Duration::from_millis(10)))
+        .build()
+        .unwrap()
+}
+
+#[tokio::main]
+#[test]
+async fn test_retry_status() -> Result<()> {
+    let (host, count) =
+        start_s3_stub(vec![("503 Service Unavailable", ""), ("200 OK", "hello")]).await;
+    let minio = stub_minio(host, RetryPolicy::default());
+    let res = minio
+        .executor(Method::GET)
+        .bucket_name("bucket")
+        .object_name("file.txt")
+        .send_ok()
+        .await?;
+    assert_eq!(res.url().path(), "/bucket/file.txt");
+    assert_eq!(res.text().await?, "hello");
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_retry_s3_code() -> Result<()> {
+    let timeout = "<Error><Code>RequestTimeout</Code><Message>timeout</Message><RequestId>1</RequestId></Error>";
+    let no_such_key =
+        "<Error><Code>NoSuchKey</Code><Message>not found</Message><RequestId>2</RequestId></Error>";
+    let responses = vec![("400 Bad Request", timeout), ("404 Not Found", no_such_key)];
+    let (host, count) = start_s3_stub(responses).await;
+    let minio = stub_minio(host, RetryPolicy::default());
+    let res = minio
+        .executor(Method::GET)
+        .bucket_name("bucket")
+        .object_name("file.txt")
+        .send()
+        .await?;
+    assert_eq!(res.status(), 404);
+    // the response rebuilt after checking the error code keeps the url.
+    assert_eq!(res.url().path(), "/bucket/file.txt");
+    assert_eq!(res.text().await?, no_such_key);
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_retry_none() -> Result<()> {
+    let (host, count) =
+        start_s3_stub(vec![("503 Service Unavailable", ""), ("200 OK", "hello")]).await;
+    let minio = stub_minio(host, RetryPolicy::none());
+    let res = minio
+        .executor(Method::GET)
+        .bucket_name("bucket")
+        .object_name("file.txt")
+        .send()
+        .await?;
+    assert_eq!(res.status(), 503);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_region_fallback_cached() -> Result<()> {
+    let no_such_bucket = "<Error><Code>NoSuchBucket</Code><Message>not found</Message><RequestId>1</RequestId></Error>";
+    let (host, count) = start_s3_stub(vec![("404 Not Found", no_such_bucket)]).await;
+    // without region, the region of bucket is discovered by `GetBucketLocation`.
+    let minio = Minio::builder()
+        .endpoint(host)
+        .secure(false)
+        .provider(StaticProvider::new("access-key", "secret-key", None))
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    for _ in 0..2 {
+        let res = minio
+            .executor(Method::GET)
+            .bucket_name("bucket")
+            .object_name("file.txt")
+            .send()
+            .await?;
+        assert_eq!(res.status(), 404);
+    }
+    // the location is requested only once, the us-east-1 fallback is cached.
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+    Ok(())
+}
+
+#[tokio::main]
+#[test]
+async fn test_region_fallback_not_cached_on_transient_error() -> Result<()> {
+    let internal_error = "<Error><Code>InternalError</Code><Message>internal error</Message><RequestId>1</RequestId></Error>";
+    let (host, count) = start_s3_stub(vec![("500 Internal Server Error", internal_error)]).await;
+    let minio = Minio::builder()
+        .endpoint(host)
+        .secure(false)
+        .provider(StaticProvider::new("access-key", "secret-key", None))
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    for _ in 0..2 {
+        let res = minio
+            .executor(Method::GET)
+            .bucket_name("bucket")
+            .object_name("file.txt")
+            .send()
+            .await?;
+        assert_eq!(res.status(), 500);
+    }
+    // the location is requested again, the fallback is not cached after a transient error.
+    assert_eq!(count.load(Ordering::SeqCst), 4);
+    Ok(())
+}